@@ -0,0 +1,90 @@
+//! Compares the allocating `pretty_format` with writing straight into a reused sink.
+
+use std::{fmt::Write as _, hint::black_box, io::Write as _};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use pretty_num::{PrettyFormatter, PrettyNumber};
+
+/// A column of numbers spanning every suffix, like a table of view counts.
+fn numbers() -> Vec<i64> {
+    (0..1_000)
+        .map(|n: i64| n.pow(6) / 7 * if n % 2 == 0 { 1 } else { -1 })
+        .collect()
+}
+
+fn write_column(c: &mut Criterion) {
+    let numbers = numbers();
+    let mut group = c.benchmark_group("column");
+
+    group.bench_function("pretty_format", |b| {
+        b.iter_batched_ref(
+            String::new,
+            |out| {
+                for &n in &numbers {
+                    out.push_str(&black_box(n).pretty_format());
+                    out.push('\n');
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("format! with pretty", |b| {
+        b.iter_batched_ref(
+            String::new,
+            |out| {
+                for &n in &numbers {
+                    writeln!(out, "{}", black_box(n).pretty()).unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("pretty_write_to", |b| {
+        b.iter_batched_ref(
+            String::new,
+            |out| {
+                for &n in &numbers {
+                    black_box(n).pretty_write_to(out).unwrap();
+                    out.push('\n');
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("pretty_write_io", |b| {
+        b.iter_batched_ref(
+            Vec::new,
+            |out| {
+                for &n in &numbers {
+                    black_box(n).pretty_write_io(out).unwrap();
+                    out.push(b'\n');
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("format_into", |b| {
+        let formatter = PrettyFormatter::new();
+        let mut buffer = [0; 32];
+        b.iter_batched_ref(
+            Vec::new,
+            |out| {
+                for &n in &numbers {
+                    let formatted = formatter.format_into(black_box(n), &mut buffer).unwrap();
+                    out.write_all(formatted.as_bytes()).unwrap();
+                    out.push(b'\n');
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, write_column);
+criterion_main!(benches);
@@ -0,0 +1,433 @@
+//! Generates `src/locale/cldr.rs` from the CLDR `numbers.json` files vendored in `cldr/`.
+//!
+//! Run `cargo run -p pretty-num-codegen` after updating the CLDR data.
+
+use std::{collections::BTreeMap, error::Error, fmt::Write, fs, path::PathBuf};
+
+use serde_json::Value;
+
+/// The locales to generate, in the order they appear in the generated file.
+const LOCALES: &[LocaleSpec] = &[
+    LocaleSpec {
+        cldr: "en",
+        name: "English",
+        constant: "EN",
+        feature: None,
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "de",
+        name: "German",
+        constant: "DE",
+        feature: Some("de"),
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "es",
+        name: "Spanish",
+        constant: "ES",
+        feature: Some("es"),
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "fr",
+        name: "French",
+        constant: "FR",
+        feature: Some("fr"),
+        plural_rule: "IntegerZeroOrOne",
+    },
+    LocaleSpec {
+        cldr: "ja",
+        name: "Japanese",
+        constant: "JA",
+        feature: Some("ja"),
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "ko",
+        name: "Korean",
+        constant: "KO",
+        feature: Some("ko"),
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "zh",
+        name: "Simplified Chinese",
+        constant: "ZH_HANS",
+        feature: Some("zh-hans"),
+        plural_rule: "ExactlyOne",
+    },
+    LocaleSpec {
+        cldr: "zh-Hant",
+        name: "Traditional Chinese",
+        constant: "ZH_HANT",
+        feature: Some("zh-hant"),
+        plural_rule: "ExactlyOne",
+    },
+];
+
+/// A locale to generate and the settings that CLDR's `numbers.json` does not contain.
+struct LocaleSpec {
+    /// The name of the locale's directory in `cldr/`.
+    cldr: &'static str,
+    /// The English name of the language, for the generated documentation.
+    name: &'static str,
+    /// The name of the generated `Locale` constant.
+    constant: &'static str,
+    /// The cargo feature the locale is compiled in with, or `None` if it always is.
+    feature: Option<&'static str>,
+    /// The `PluralRule` matching the language's CLDR plural rules for the `one` category.
+    plural_rule: &'static str,
+}
+
+/// A suffix table read from a CLDR compact decimal format.
+#[derive(Debug, PartialEq, Eq)]
+struct Table {
+    plural: Vec<String>,
+    singular: Vec<String>,
+    separator: String,
+    exponents: Vec<u32>,
+    thresholds: Vec<u32>,
+}
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn main() -> Result<()> {
+    let path = repository().join("src/locale/cldr.rs");
+    fs::write(&path, generate()?)?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+/// Returns the root of the pretty-num repository.
+fn repository() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the codegen crate is inside the repository")
+        .to_path_buf()
+}
+
+/// Generates the source of `src/locale/cldr.rs`.
+fn generate() -> Result<String> {
+    let mut source = String::from(
+        "// @generated by `cargo run -p pretty-num-codegen` from the CLDR data in `cldr/`. Do not edit by hand.\n\
+         \n\
+         use super::Locale;\n\
+         use crate::{PluralRule, Suffixes};\n\
+         \n\
+         impl<'a> Locale<'a> {\n",
+    );
+
+    for (index, spec) in LOCALES.iter().enumerate() {
+        let path = repository()
+            .join("cldr")
+            .join(spec.cldr)
+            .join("numbers.json");
+        let json: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let numbers = &json["main"][spec.cldr]["numbers"];
+        let decimal = numbers["symbols-numberSystem-latn"]["decimal"]
+            .as_str()
+            .and_then(|decimal| decimal.parse::<char>().ok())
+            .ok_or_else(|| format!("{}: missing decimal separator", spec.cldr))?;
+        let formats = &numbers["decimalFormats-numberSystem-latn"];
+        let short = read_table(&formats["short"]["decimalFormat"])
+            .map_err(|error| format!("{} short: {error}", spec.cldr))?;
+        let long = read_table(&formats["long"]["decimalFormat"])
+            .map_err(|error| format!("{} long: {error}", spec.cldr))?;
+
+        if index > 0 {
+            source.push('\n');
+        }
+        writeln!(
+            source,
+            "    /// {}, generated from the CLDR data for `{}`.",
+            spec.name, spec.cldr
+        )?;
+        if let Some(feature) = spec.feature {
+            writeln!(source, "    #[cfg(feature = \"{feature}\")]")?;
+        }
+        writeln!(
+            source,
+            "    pub const {}: Locale<'static> = Locale::new(",
+            spec.constant
+        )?;
+        writeln!(source, "        {decimal:?},")?;
+        write_table(&mut source, &short, spec.plural_rule)?;
+        write_table(&mut source, &long, spec.plural_rule)?;
+        source.push_str("    );\n");
+    }
+
+    source.push_str("}\n");
+    Ok(source)
+}
+
+/// Reads a suffix table from the patterns of a CLDR compact decimal format, e.g. `"1000000-count-one": "0 Million"`.
+///
+/// Each suffix's exponent is the power of ten it divides by: `"0000 M"` at 10^9 is still millions.
+/// Its threshold is the power of ten it is first used at, e.g. 10 for Spanish `"00 mil M"`, which divides by 10^9.
+/// Thresholds without a suffix, such as German thousands, are left uncompacted.
+/// The `one` category becomes the singular form and `other` the plural form; the remaining categories must match `other`.
+fn read_table(format: &Value) -> Result<Table> {
+    let format = format.as_object().ok_or("missing compact decimal format")?;
+    let mut thresholds: BTreeMap<u32, BTreeMap<&str, &str>> = BTreeMap::new();
+
+    for (key, pattern) in format {
+        let (threshold, category) = key
+            .split_once("-count-")
+            .ok_or_else(|| format!("unexpected key {key:?}"))?;
+        if threshold.contains("-alt-") || category.contains("-alt-") {
+            continue;
+        }
+        let power = threshold.len() as u32 - 1;
+        if threshold != format!("1{}", "0".repeat(power as usize)) {
+            return Err(format!("unexpected threshold {threshold:?}").into());
+        }
+        let pattern = pattern
+            .as_str()
+            .ok_or_else(|| format!("pattern {key:?} is not a string"))?;
+        thresholds
+            .entry(power)
+            .or_default()
+            .insert(category, pattern);
+    }
+
+    let mut table = Table {
+        plural: Vec::new(),
+        singular: Vec::new(),
+        separator: String::new(),
+        exponents: Vec::new(),
+        thresholds: Vec::new(),
+    };
+
+    for (power, patterns) in thresholds {
+        let other = *patterns
+            .get("other")
+            .ok_or_else(|| format!("10^{power} has no `other` pattern"))?;
+        for (category, pattern) in &patterns {
+            if !matches!(*category, "one" | "other") && *pattern != other {
+                return Err(format!("10^{power} has a distinct `{category}` pattern").into());
+            }
+        }
+
+        let (zeros, separator, suffix) = parse_pattern(other)?;
+        if suffix.is_empty() {
+            continue;
+        }
+
+        let exponent = power + 1 - zeros;
+        if table.plural.last() == Some(&suffix) {
+            if table.exponents.last() != Some(&exponent) {
+                return Err(format!("{suffix:?} is used for more than one power of ten").into());
+            }
+            continue;
+        }
+        if table.plural.is_empty() {
+            table.separator = separator.clone();
+        } else if table.separator != separator {
+            return Err(format!("{suffix:?} has a different separator").into());
+        }
+
+        let singular = match patterns.get("one") {
+            Some(one) => parse_pattern(one)?.2,
+            None => suffix.clone(),
+        };
+        table.plural.push(suffix);
+        table.singular.push(singular);
+        table.exponents.push(exponent);
+        table.thresholds.push(power);
+    }
+
+    if table.plural.is_empty() {
+        return Err("no suffixes".into());
+    }
+    Ok(table)
+}
+
+/// Splits a pattern such as `"00 Mio'.'"` into its number of zeros, its separator and its unquoted suffix.
+fn parse_pattern(pattern: &str) -> Result<(u32, String, String)> {
+    let rest = pattern.trim_start_matches('0');
+    let zeros = (pattern.len() - rest.len()) as u32;
+    if zeros == 0 {
+        return Err(format!("pattern {pattern:?} does not start with the number").into());
+    }
+
+    let suffix = rest.trim_start();
+    let separator = rest[..rest.len() - suffix.len()].to_string();
+    Ok((zeros, separator, unquote(suffix)))
+}
+
+/// Removes CLDR's quoting of literal characters, e.g. `Mio'.'` becomes `Mio.` and `''` becomes `'`.
+fn unquote(suffix: &str) -> String {
+    let mut unquoted = String::new();
+    let mut chars = suffix.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                unquoted.push('\'');
+            }
+            '\'' => {}
+            c => unquoted.push(c),
+        }
+    }
+
+    unquoted
+}
+
+/// Writes a table as a call to `Suffixes::localized`, followed by its group size, exponents and thresholds if they are not the default.
+fn write_table(source: &mut String, table: &Table, plural_rule: &str) -> Result<()> {
+    let singular = if table.singular == table.plural {
+        String::from("None")
+    } else {
+        format!("Some(&[{}])", string_list(&table.singular))
+    };
+
+    writeln!(source, "        Suffixes::localized(")?;
+    writeln!(source, "            &[{}],", string_list(&table.plural))?;
+    writeln!(source, "            {singular},")?;
+    writeln!(source, "            {},", string_literal(&table.separator))?;
+    writeln!(source, "            PluralRule::{plural_rule},")?;
+    write!(source, "        )")?;
+
+    let group_size = table.exponents[0];
+    let uniform = (1..)
+        .zip(&table.exponents)
+        .all(|(level, &exponent)| exponent == group_size * level);
+    if !uniform {
+        let exponents: Vec<String> = table.exponents.iter().map(u32::to_string).collect();
+        write!(source, "\n        .laddered(&[{}])", exponents.join(", "))?;
+    } else if group_size != 3 {
+        write!(source, "\n        .grouped_by({group_size})")?;
+    }
+    if table.thresholds != table.exponents {
+        let thresholds: Vec<String> = table.thresholds.iter().map(u32::to_string).collect();
+        write!(
+            source,
+            "\n        .starting_at(&[{}])",
+            thresholds.join(", ")
+        )?;
+    }
+
+    source.push_str(",\n");
+    Ok(())
+}
+
+/// Formats strings as a comma-separated list of Rust string literals.
+fn string_list(strings: &[String]) -> String {
+    strings
+        .iter()
+        .map(|string| string_literal(string))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats a string as a Rust string literal, escaping whitespace other than spaces so that it stays visible.
+fn string_literal(string: &str) -> String {
+    let mut literal = String::from('"');
+
+    for c in string.chars() {
+        match c {
+            '"' | '\\' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            ' ' => literal.push(' '),
+            c if c.is_whitespace() || c.is_control() => {
+                write!(literal, "\\u{{{:x}}}", c as u32).expect("writing to a String cannot fail");
+            }
+            c => literal.push(c),
+        }
+    }
+
+    literal.push('"');
+    literal
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, parse_pattern, read_table, repository, string_literal, Table};
+    use serde_json::json;
+
+    #[test]
+    fn generated_tables_are_up_to_date() {
+        let generated = generate().unwrap();
+        let committed = std::fs::read_to_string(repository().join("src/locale/cldr.rs")).unwrap();
+
+        assert!(
+            generated == committed,
+            "src/locale/cldr.rs is out of date, run `cargo run -p pretty-num-codegen`"
+        );
+    }
+
+    #[test]
+    fn parse_pattern_test() {
+        assert_eq!(
+            parse_pattern("00\u{a0}Mio'.'").unwrap(),
+            (2, String::from("\u{a0}"), String::from("Mio."))
+        );
+        assert_eq!(
+            parse_pattern("0").unwrap(),
+            (1, String::new(), String::new())
+        );
+        assert!(parse_pattern("K0").is_err());
+    }
+
+    #[test]
+    fn read_table_test() {
+        let format = json!({
+            "1000-count-one": "0",
+            "1000-count-other": "0",
+            "1000000-count-one": "0 Million",
+            "1000000-count-other": "0 Millionen",
+            "10000000-count-one": "00 Millionen",
+            "10000000-count-other": "00 Millionen",
+            "1000000000-count-one": "0000 Millionen",
+            "1000000000-count-other": "0000 Millionen",
+            "10000000000-count-one": "0 Milliarde",
+            "10000000000-count-many": "0 Milliarden",
+            "10000000000-count-other": "0 Milliarden",
+        });
+
+        assert_eq!(
+            read_table(&format).unwrap(),
+            Table {
+                plural: vec![String::from("Millionen"), String::from("Milliarden")],
+                singular: vec![String::from("Million"), String::from("Milliarde")],
+                separator: String::from(" "),
+                exponents: vec![6, 10],
+                thresholds: vec![6, 10],
+            }
+        );
+    }
+
+    #[test]
+    fn read_table_thresholds_test() {
+        let format = json!({
+            "1000000-count-other": "0 M",
+            "1000000000-count-other": "0000 M",
+            "10000000000-count-other": "00 mil M",
+            "1000000000000-count-other": "0 B",
+        });
+
+        let table = read_table(&format).unwrap();
+        assert_eq!(table.exponents, vec![6, 9, 12]);
+        assert_eq!(table.thresholds, vec![6, 10, 12]);
+    }
+
+    #[test]
+    fn read_table_rejects_distinct_categories_test() {
+        let format = json!({
+            "1000000-count-few": "0 miliony",
+            "1000000-count-other": "0 milionów",
+        });
+
+        assert!(read_table(&format).is_err());
+    }
+
+    #[test]
+    fn string_literal_test() {
+        assert_eq!(string_literal("mil\u{a0}M"), "\"mil\\u{a0}M\"");
+        assert_eq!(string_literal("万亿"), "\"万亿\"");
+    }
+}
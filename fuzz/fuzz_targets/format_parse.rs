@@ -0,0 +1,46 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pretty_num::{parse_pretty, Overflow, PrettyFormatter, PrettyNumber};
+
+const FORMATTERS: [PrettyFormatter; 5] = [
+    PrettyFormatter::new(),
+    PrettyFormatter::new()
+        .max_decimals(2)
+        .overflow(Overflow::Scientific),
+    PrettyFormatter::bytes(),
+    PrettyFormatter::binary_bytes(),
+    PrettyFormatter::si(),
+];
+
+fuzz_target!(|input: (i64, f64, u8, &str)| {
+    let (integer, float, choice, text) = input;
+    let formatter = FORMATTERS[usize::from(choice) % FORMATTERS.len()];
+
+    // Formatting never panics, and the text of every integer that formats reads back to the integers displayed the same way.
+    if let Ok(formatted) = formatter.try_format(integer) {
+        let range = formatter
+            .range_of(integer)
+            .expect("formatted numbers have a range");
+        assert!(range.contains(&integer), "{integer} is outside {range:?}");
+        assert_eq!(
+            formatter.parse_range(&formatted),
+            Ok(range),
+            "{integer} was formatted as {formatted}"
+        );
+
+        if let Ok(parsed) = formatter.parser().parse(&formatted) {
+            assert_eq!(
+                formatter.try_format(parsed).as_deref(),
+                Ok(formatted.as_str())
+            );
+        }
+    }
+    let _ = formatter.try_format(float);
+    let _ = float.try_pretty_format();
+
+    // Parsing arbitrary text never panics.
+    let _ = parse_pretty(text);
+    let _ = formatter.parser().case_insensitive(true).parse(text);
+    let _ = formatter.parse_range(text);
+});
@@ -0,0 +1,314 @@
+use core::{fmt, ops::Deref, str};
+
+/// A fixed-capacity string stored inline, for formatting numbers without allocating, e.g. on embedded targets without `alloc`.
+///
+/// Writes that do not fit fail with [`fmt::Error`] and leave the contents unchanged, so the string is never cut off mid-character.
+/// A `write!` is undone as a whole, even if its first pieces fit.
+/// # Examples
+/// ```
+/// # use pretty_num::{PrettyNumber, StackString};
+/// use core::fmt::Write;
+///
+/// let mut label = StackString::<16>::new();
+/// write!(label, "{} views", 23_520_123.pretty()).unwrap();
+/// assert_eq!(label.as_str(), "23.5M views");
+///
+/// // Writes that do not fit are rejected, including the pieces that fit.
+/// assert!(write!(label, " and {}", 1_500.pretty()).is_err());
+/// assert_eq!(label.as_str(), "23.5M views");
+/// ```
+#[derive(Clone, Copy)]
+pub struct StackString<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackString<N> {
+    /// Creates an empty string with a capacity of `N` bytes.
+    pub const fn new() -> Self {
+        StackString {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the contents of the string.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.len]).expect("only whole strings are written")
+    }
+
+    /// Returns the number of bytes the string can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Empties the string so that it can be reused.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for StackString<N> {
+    fn default() -> Self {
+        StackString::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for StackString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push(&mut self.bytes, &mut self.len, s)
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        let len = self.len;
+        let result = fmt::write(self, args);
+        if result.is_err() {
+            self.len = len;
+        }
+        result
+    }
+}
+
+impl<const N: usize> Deref for StackString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for StackString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for StackString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for StackString<N> {}
+
+impl<const N: usize> PartialEq<str> for StackString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for StackString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> fmt::Debug for StackString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for StackString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Writes into a caller-provided byte slice, for [`PrettyFormatter::format_into`](crate::PrettyFormatter::format_into).
+pub(crate) struct SliceWriter<'b> {
+    bytes: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> SliceWriter<'b> {
+    /// Creates a writer that starts at the beginning of `bytes`.
+    pub(crate) fn new(bytes: &'b mut [u8]) -> Self {
+        SliceWriter { bytes, len: 0 }
+    }
+
+    /// Returns the written part of the slice.
+    pub(crate) fn into_str(self) -> &'b str {
+        let bytes: &'b [u8] = self.bytes;
+        str::from_utf8(&bytes[..self.len]).expect("only whole strings are written")
+    }
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push(self.bytes, &mut self.len, s)
+    }
+}
+
+/// Counts the bytes written into another [`fmt::Write`] sink.
+pub(crate) struct Counter<W> {
+    inner: W,
+    written: usize,
+}
+
+impl<W: fmt::Write> Counter<W> {
+    /// Creates a counter that has not written anything yet.
+    pub(crate) fn new(inner: W) -> Self {
+        Counter { inner, written: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub(crate) fn written(&self) -> usize {
+        self.written
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for Counter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_str(s)?;
+        self.written += s.len();
+        Ok(())
+    }
+}
+
+/// Writes into an [`io::Write`](std::io::Write) sink as a [`fmt::Write`] one, keeping the I/O error that [`fmt::Error`] cannot carry.
+#[cfg(feature = "std")]
+pub(crate) struct IoWriter<W> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+    /// Creates a writer into `inner`.
+    pub(crate) fn new(inner: W) -> Self {
+        IoWriter { inner, error: None }
+    }
+
+    /// Returns the I/O error that made a write fail, if any.
+    pub(crate) fn into_error(self) -> Option<std::io::Error> {
+        self.error
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Checks that what is written matches some text, without storing it.
+pub(crate) struct Matcher<'s> {
+    remaining: Option<&'s str>,
+}
+
+impl<'s> Matcher<'s> {
+    /// Creates a matcher expecting `expected` to be written.
+    pub(crate) fn new(expected: &'s str) -> Self {
+        Matcher {
+            remaining: Some(expected),
+        }
+    }
+
+    /// Returns whether exactly the expected text was written.
+    pub(crate) fn is_match(&self) -> bool {
+        self.remaining == Some("")
+    }
+}
+
+impl fmt::Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // A mismatch fails the write, so that nothing more is compared.
+        self.remaining = self
+            .remaining
+            .and_then(|remaining| remaining.strip_prefix(s));
+        self.remaining.map(|_| ()).ok_or(fmt::Error)
+    }
+}
+
+/// Appends `s` after the first `len` bytes of `bytes`, or fails without writing anything if it does not fit.
+fn push(bytes: &mut [u8], len: &mut usize, s: &str) -> fmt::Result {
+    let end = *len + s.len();
+    bytes
+        .get_mut(*len..end)
+        .ok_or(fmt::Error)?
+        .copy_from_slice(s.as_bytes());
+    *len = end;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Matcher, SliceWriter, StackString};
+    use crate::PrettyNumber;
+    use core::fmt::Write;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&["1.5", "k"], Ok("1.5k"))]
+    #[case(&["1.5", "µ"], Ok("1.5µ"))]
+    #[case(&["23.5", "µs"], Err("23.5"))]
+    #[case(&["", ""], Ok(""))]
+    fn stack_string_test(#[case] writes: &[&str], #[case] expected: Result<&str, &str>) {
+        let mut string = StackString::<5>::new();
+        let result = writes.iter().try_for_each(|s| string.write_str(s));
+
+        assert_eq!(
+            result.map(|_| string.as_str()),
+            expected.map_err(|_| core::fmt::Error)
+        );
+        assert_eq!(string.as_str(), expected.unwrap_or_else(|s| s));
+    }
+
+    // The digits of "23.5M" fit, but its suffix does not.
+    #[rstest]
+    #[case("", 1_500, Ok("1.5k"))]
+    #[case("", 23_520_123, Err(""))]
+    #[case("a", 1_500, Err("a"))]
+    fn stack_string_write_fmt_test(
+        #[case] initial: &str,
+        #[case] number: i64,
+        #[case] expected: Result<&str, &str>,
+    ) {
+        let mut string = StackString::<4>::new();
+        string.write_str(initial).unwrap();
+        let result = write!(string, "{}", number.pretty());
+
+        assert_eq!(result.is_ok(), expected.is_ok());
+        assert_eq!(string.as_str(), expected.unwrap_or_else(|s| s));
+    }
+
+    #[test]
+    fn stack_string_clear_test() {
+        let mut string = StackString::<8>::new();
+        write!(string, "1.5k").unwrap();
+        string.clear();
+        write!(string, "2M").unwrap();
+
+        assert_eq!(string, "2M");
+        assert_eq!(string.capacity(), 8);
+    }
+
+    #[rstest]
+    #[case(&["1.5", "k"], true)]
+    #[case(&["1.5"], false)]
+    #[case(&["1.5", "kB"], false)]
+    #[case(&["1", ".5", "k"], true)]
+    #[case(&["1.6", "k"], false)]
+    fn matcher_test(#[case] writes: &[&str], #[case] expected: bool) {
+        let mut matcher = Matcher::new("1.5k");
+        let _ = writes.iter().try_for_each(|s| matcher.write_str(s));
+
+        assert_eq!(matcher.is_match(), expected);
+    }
+
+    #[test]
+    fn slice_writer_test() {
+        let mut bytes = [0; 8];
+        let mut writer = SliceWriter::new(&mut bytes);
+
+        assert!(write!(writer, "23.5M").is_ok());
+        assert!(write!(writer, "+ab").is_ok());
+        assert!(write!(writer, "x").is_err());
+        assert_eq!(writer.into_str(), "23.5M+ab");
+    }
+}
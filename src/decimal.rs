@@ -0,0 +1,314 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Write},
+};
+
+use crate::RoundingMode;
+
+/// An exact non-negative decimal number, equal to `digits * 10^exponent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal {
+    pub digits: u128,
+    pub exponent: i32,
+}
+
+impl Decimal {
+    /// Creates a decimal equal to an integer magnitude.
+    pub fn from_integer(magnitude: u128) -> Self {
+        Decimal {
+            digits: magnitude,
+            exponent: 0,
+        }
+    }
+
+    /// Creates a decimal from the magnitude of a finite float.
+    ///
+    /// The float's shortest round-trip representation is used, so `0.1 + 0.2` is read as `0.30000000000000004` rather than its exact binary value.
+    pub fn from_float(float: impl fmt::LowerExp) -> Self {
+        let mut parser = LowerExpParser::default();
+        write!(parser, "{float:e}").expect("LowerExp output is always parseable");
+
+        let exponent = if parser.negative_exponent {
+            -parser.exponent
+        } else {
+            parser.exponent
+        };
+        Decimal {
+            digits: parser.digits,
+            exponent: exponent - parser.fraction_digits,
+        }
+    }
+
+    /// Returns whether the decimal is zero.
+    pub fn is_zero(self) -> bool {
+        self.digits == 0
+    }
+
+    /// Returns the power of ten of the leading digit, e.g. 2 for 523 and -1 for 0.37.
+    ///
+    /// The decimal must not be zero.
+    pub fn leading_exponent(self) -> i32 {
+        self.digits.ilog10() as i32 + self.exponent
+    }
+
+    /// Returns whether the decimal is at least `multiplier * 10^power`.
+    pub fn at_least(self, multiplier: u128, power: i32) -> bool {
+        let shift = self.exponent - power;
+
+        if multiplier == 1 {
+            !self.is_zero() && self.leading_exponent() >= power
+        } else if shift >= 0 {
+            10u128
+                .checked_pow(shift as u32)
+                .and_then(|scale| self.digits.checked_mul(scale))
+                .map_or(true, |scaled| scaled >= multiplier)
+        } else {
+            10u128
+                .checked_pow(shift.unsigned_abs())
+                .and_then(|scale| multiplier.checked_mul(scale))
+                .is_some_and(|scaled| self.digits >= scaled)
+        }
+    }
+
+    /// Returns the number of digits in the integer part of the decimal divided by `divisor * 10^power`, counting a zero integer part as one digit.
+    pub fn integer_digits(self, divisor: u128, power: i32) -> i32 {
+        if divisor == 1 {
+            (self.leading_exponent() - power + 1).max(1)
+        } else {
+            self.scale_rounded(divisor, power, Rounding::TowardZero)
+                .checked_ilog10()
+                .map_or(1, |log| log as i32 + 1)
+        }
+    }
+
+    /// Divides the decimal by `divisor * 10^power`, rounding to an integer.
+    ///
+    /// The result must fit in a `u128`, and `divisor * 10` must not overflow.
+    pub fn scale_rounded(self, divisor: u128, power: i32, rounding: Rounding) -> u128 {
+        let shift = self.exponent - power;
+
+        if shift >= 0 && divisor == 1 {
+            self.digits * 10u128.pow(shift as u32)
+        } else if shift >= 0 {
+            // Long division keeps the intermediate values small when the divisor is not a power of ten.
+            let mut quotient = self.digits / divisor;
+            let mut remainder = self.digits % divisor;
+            for _ in 0..shift {
+                quotient = quotient * 10 + remainder * 10 / divisor;
+                remainder = remainder * 10 % divisor;
+            }
+            round_quotient(quotient, remainder, divisor, rounding)
+        } else {
+            match 10u128
+                .checked_pow(shift.unsigned_abs())
+                .and_then(|scale| divisor.checked_mul(scale))
+            {
+                Some(divisor) => divide_rounded(self.digits, divisor, rounding),
+                // The divisor is larger than any `u128`, so the quotient is less than one half.
+                None if self.digits > 0 && rounding == Rounding::AwayFromZero => 1,
+                None => 0,
+            }
+        }
+    }
+}
+
+/// How to round a magnitude, once the sign of the number has been taken into account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    HalfAwayFromZero,
+    HalfEven,
+    TowardZero,
+    AwayFromZero,
+}
+
+impl Rounding {
+    /// Returns how to round the magnitude of a number with the given sign.
+    pub fn new(mode: RoundingMode, negative: bool) -> Self {
+        match mode {
+            RoundingMode::HalfUp => Rounding::HalfAwayFromZero,
+            RoundingMode::HalfEven => Rounding::HalfEven,
+            RoundingMode::TowardZero => Rounding::TowardZero,
+            RoundingMode::Floor if negative => Rounding::AwayFromZero,
+            RoundingMode::Floor => Rounding::TowardZero,
+            RoundingMode::Ceil if negative => Rounding::TowardZero,
+            RoundingMode::Ceil => Rounding::AwayFromZero,
+        }
+    }
+}
+
+/// Divides `dividend` by `divisor`, rounding the quotient as specified.
+pub(crate) fn divide_rounded(dividend: u128, divisor: u128, rounding: Rounding) -> u128 {
+    round_quotient(dividend / divisor, dividend % divisor, divisor, rounding)
+}
+
+/// Rounds `quotient + remainder / divisor` to an integer as specified.
+fn round_quotient(quotient: u128, remainder: u128, divisor: u128, rounding: Rounding) -> u128 {
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let round_away = match rounding {
+        Rounding::HalfAwayFromZero => remainder >= divisor - remainder,
+        Rounding::HalfEven => match remainder.cmp(&(divisor - remainder)) {
+            Ordering::Less => false,
+            Ordering::Equal => quotient % 2 == 1,
+            Ordering::Greater => true,
+        },
+        Rounding::TowardZero => false,
+        Rounding::AwayFromZero => true,
+    };
+
+    if round_away {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Reads the `LowerExp` output of a float as it is written, e.g. `-1.23456e3`, without allocating a `String`.
+#[derive(Default)]
+struct LowerExpParser {
+    digits: u128,
+    fraction_digits: i32,
+    exponent: i32,
+    negative_exponent: bool,
+    in_fraction: bool,
+    in_exponent: bool,
+}
+
+impl fmt::Write for LowerExpParser {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            match (character, character.to_digit(10)) {
+                // A float's mantissa has at most 17 digits and its exponent at most 3, so neither can overflow.
+                (_, Some(digit)) if self.in_exponent => {
+                    self.exponent = self.exponent * 10 + digit as i32
+                }
+                (_, Some(digit)) => {
+                    self.digits = self.digits * 10 + digit as u128;
+                    self.fraction_digits += self.in_fraction as i32;
+                }
+                ('-', _) if self.in_exponent => self.negative_exponent = true,
+                // The sign of the mantissa is ignored, since only the magnitude is read.
+                ('-', _) => {}
+                ('.', _) => self.in_fraction = true,
+                ('e', _) => self.in_exponent = true,
+                _ => return Err(fmt::Error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Decimal, Rounding};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0.37, 37, -2)]
+    #[case(-1234.56, 123456, -2)]
+    #[case(0.1 + 0.2, 30000000000000004, -17)]
+    #[case(1e300, 1, 300)]
+    #[case(5e-324, 5, -324)]
+    #[case(0.0, 0, 0)]
+    fn from_float_test(#[case] float: f64, #[case] digits: u128, #[case] exponent: i32) {
+        assert_eq!(Decimal::from_float(float), Decimal { digits, exponent });
+    }
+
+    #[rstest]
+    #[case(Decimal { digits: 1_450, exponent: 0 }, 2, 15)]
+    #[case(Decimal { digits: 1_449, exponent: 0 }, 2, 14)]
+    #[case(Decimal { digits: 37, exponent: -2 }, -2, 37)]
+    #[case(Decimal { digits: 37, exponent: -2 }, -1, 4)]
+    #[case(Decimal { digits: 12, exponent: 3 }, 2, 120)]
+    #[case(Decimal { digits: u128::MAX, exponent: -50 }, 0, 0)]
+    fn scale_rounded_test(#[case] decimal: Decimal, #[case] power: i32, #[case] expected: u128) {
+        assert_eq!(
+            decimal.scale_rounded(1, power, Rounding::HalfAwayFromZero),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(1_250, Rounding::HalfAwayFromZero, 13)]
+    #[case(1_250, Rounding::HalfEven, 12)]
+    #[case(1_350, Rounding::HalfEven, 14)]
+    #[case(1_251, Rounding::HalfEven, 13)]
+    #[case(1_299, Rounding::TowardZero, 12)]
+    #[case(1_201, Rounding::AwayFromZero, 13)]
+    #[case(1_200, Rounding::AwayFromZero, 12)]
+    fn scale_rounded_modes_test(
+        #[case] digits: u128,
+        #[case] rounding: Rounding,
+        #[case] expected: u128,
+    ) {
+        let decimal = Decimal {
+            digits,
+            exponent: 0,
+        };
+
+        assert_eq!(decimal.scale_rounded(1, 2, rounding), expected);
+    }
+
+    #[test]
+    fn scale_rounded_tiny_away_from_zero_test() {
+        let decimal = Decimal {
+            digits: 1,
+            exponent: -50,
+        };
+
+        assert_eq!(decimal.scale_rounded(1, 0, Rounding::AwayFromZero), 1);
+    }
+
+    #[rstest]
+    #[case(Decimal { digits: 1_536, exponent: 0 }, 1_024, 0, 2)]
+    #[case(Decimal { digits: 1_536, exponent: 0 }, 1_024, -1, 15)]
+    #[case(Decimal { digits: 1_535, exponent: 0 }, 1_024, -3, 1_499)]
+    #[case(Decimal { digits: 15, exponent: 2 }, 1_024, -2, 146)]
+    #[case(Decimal { digits: 1_536, exponent: -3 }, 1_024, -3, 2)]
+    #[case(Decimal { digits: 3_000, exponent: 0 }, 1_000, 0, 3)]
+    fn scale_rounded_divisor_test(
+        #[case] decimal: Decimal,
+        #[case] divisor: u128,
+        #[case] power: i32,
+        #[case] expected: u128,
+    ) {
+        assert_eq!(
+            decimal.scale_rounded(divisor, power, Rounding::HalfAwayFromZero),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Decimal { digits: 523, exponent: 3 }, 1, 3, 3)]
+    #[case(Decimal { digits: 5, exponent: 2 }, 1, 3, 1)]
+    #[case(Decimal { digits: 1_048_575, exponent: 0 }, 1_024, 0, 4)]
+    #[case(Decimal { digits: 1_048_575, exponent: 0 }, 1_048_576, 0, 1)]
+    fn integer_digits_test(
+        #[case] decimal: Decimal,
+        #[case] divisor: u128,
+        #[case] power: i32,
+        #[case] expected: i32,
+    ) {
+        assert_eq!(decimal.integer_digits(divisor, power), expected);
+    }
+
+    #[rstest]
+    #[case(Decimal { digits: 1_024, exponent: 0 }, 1_024, 0, true)]
+    #[case(Decimal { digits: 1_023, exponent: 0 }, 1_024, 0, false)]
+    #[case(Decimal { digits: 1, exponent: 3 }, 1, 3, true)]
+    #[case(Decimal { digits: 999, exponent: 0 }, 1, 3, false)]
+    #[case(Decimal { digits: 5, exponent: -1 }, 1, 0, false)]
+    #[case(Decimal { digits: 1, exponent: 300 }, 1_024, 0, true)]
+    #[case(Decimal { digits: u128::MAX, exponent: -300 }, 1, 0, false)]
+    fn at_least_test(
+        #[case] decimal: Decimal,
+        #[case] multiplier: u128,
+        #[case] power: i32,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(decimal.at_least(multiplier, power), expected);
+    }
+}
@@ -0,0 +1,208 @@
+use core::fmt::{self, Write};
+
+use crate::{PrettyFormatter, PrettyNumber, MAX_PRECISION};
+
+/// A number that is formatted prettily when displayed, writing directly into the output without allocating a `String`.
+///
+/// Created by [`PrettyNumber::pretty`] or [`PrettyFormatter::display`].
+///
+/// Format specifiers are honored like for any other number: width, fill and alignment pad the whole text including the suffix,
+/// `+` shows the sign of positive numbers, `0` pads with zeros after the sign, and a precision displays exactly that many decimals.
+///
+/// Displaying fails with [`fmt::Error`] where [`PrettyFormatter::try_format`] would return an error,
+/// so `to_string` and `format!` panic on such numbers like [`PrettyFormatter::format`] does.
+/// # Examples
+/// ```
+/// # use pretty_num::PrettyNumber;
+/// use std::fmt::Write;
+///
+/// let mut out = String::new();
+/// write!(out, "{} views", 23_520_123.pretty()).unwrap();
+/// assert_eq!(out, "23.5M views");
+///
+/// // Numbers are right-aligned by default.
+/// assert_eq!(format!("[{:>8}]", 1_500.pretty()), "[    1.5k]");
+/// assert_eq!(format!("[{:*<8}]", 1_500.pretty()), "[1.5k****]");
+/// assert_eq!(format!("[{:^+8}]", 1_500.pretty()), "[ +1.5k  ]");
+/// assert_eq!(format!("{:.2}", 23_520_123.pretty()), "23.52M");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pretty<'a, N> {
+    number: N,
+    formatter: PrettyFormatter<'a>,
+}
+
+impl<'a, N: PrettyNumber> Pretty<'a, N> {
+    /// Wraps a number to be displayed with a formatter.
+    pub(crate) const fn new(number: N, formatter: PrettyFormatter<'a>) -> Self {
+        Pretty { number, formatter }
+    }
+
+    /// Returns the wrapped number.
+    pub const fn number(&self) -> N {
+        self.number
+    }
+
+    /// Returns the formatter the number is displayed with.
+    pub const fn formatter(&self) -> PrettyFormatter<'a> {
+        self.formatter
+    }
+}
+
+impl<N: PrettyNumber> fmt::Display for Pretty<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatter = match f.precision() {
+            Some(precision) => self
+                .formatter
+                .fixed_decimals(precision.min(MAX_PRECISION as usize) as u8),
+            None => self.formatter,
+        };
+        let rounded = formatter.round(self.number).map_err(|_| fmt::Error)?;
+        let sign = match (rounded.is_negative(), f.sign_plus()) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+
+        let Some(width) = f.width() else {
+            f.write_str(sign)?;
+            return rounded.write_unsigned(f);
+        };
+
+        // The text is measured first, so that it can be padded without being buffered.
+        let mut length = CharCount(sign.len());
+        rounded.write_unsigned(&mut length)?;
+        let padding = width.saturating_sub(length.0);
+
+        if f.sign_aware_zero_pad() {
+            f.write_str(sign)?;
+            write_fill(f, '0', padding)?;
+            return rounded.write_unsigned(f);
+        }
+
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+        let fill = f.fill();
+        write_fill(f, fill, before)?;
+        f.write_str(sign)?;
+        rounded.write_unsigned(f)?;
+        write_fill(f, fill, after)
+    }
+}
+
+/// Counts the characters written, which is how format specifiers measure width.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `count` fill characters.
+fn write_fill(f: &mut fmt::Formatter<'_>, fill: char, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| f.write_char(fill))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Overflow, PrettyFormatter, PrettyNumber, Suffixes};
+    use rstest::rstest;
+    use std::fmt::Write;
+
+    #[rstest]
+    #[case(PrettyFormatter::new(), 0, "0")]
+    #[case(PrettyFormatter::new(), -25_621_783, "-25.6M")]
+    #[case(PrettyFormatter::new().min_decimals(2), 7, "7.00")]
+    #[case(PrettyFormatter::new().min_decimals(2), 1_500, "1.50k")]
+    #[case(PrettyFormatter::new().overflow(Overflow::Saturate).min_decimals(1), i128::MAX, "999Qi+")]
+    #[case(PrettyFormatter::new().overflow(Overflow::Scientific).min_decimals(2), -i128::MAX, "-1.70e38")]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::LONG), 1_000_000, "1 million")]
+    #[case(PrettyFormatter::binary_bytes(), 1_610_612_736, "1.5 GiB")]
+    #[case(PrettyFormatter::bytes(), 0, "0 B")]
+    fn display_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: i128,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(formatter.display(input).to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(PrettyFormatter::new(), 0.000_42, "0.00042")]
+    #[case(PrettyFormatter::new().min_decimals(3), 0.5, "0.500")]
+    #[case(PrettyFormatter::new().min_decimals(3), -0.0, "0.000")]
+    #[case(PrettyFormatter::si().unit("s"), 0.000_42, "420µs")]
+    #[case(PrettyFormatter::si().unit("s"), 4e-15, "0.004ps")]
+    fn display_float_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: f64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(formatter.display(input).to_string(), expected);
+    }
+
+    #[test]
+    fn display_smallest_f64_test() {
+        let expected = format!("0.{}5", "0".repeat(323));
+
+        assert_eq!(5e-324.pretty().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(format!("[{:8}]", 1_500.pretty()), "[    1.5k]")]
+    #[case(format!("[{:>8}]", 1_500.pretty()), "[    1.5k]")]
+    #[case(format!("[{:<8}]", 1_500.pretty()), "[1.5k    ]")]
+    #[case(format!("[{:^8}]", 1_500.pretty()), "[  1.5k  ]")]
+    #[case(format!("[{:^7}]", 1_500.pretty()), "[ 1.5k  ]")]
+    #[case(format!("[{:_>8}]", (-1_500).pretty()), "[___-1.5k]")]
+    #[case(format!("[{:·<8}]", 0.000_42.pretty()), "[0.00042·]")]
+    #[case(format!("[{:2}]", 23_520_123.pretty()), "[23.5M]")]
+    #[case(format!("[{:>6}]", PrettyFormatter::si().unit("s").display(0.000_42)), "[ 420µs]")]
+    #[case(format!("[{:+}]", 1_500.pretty()), "[+1.5k]")]
+    #[case(format!("[{:+}]", 0.pretty()), "[+0]")]
+    #[case(format!("[{:+}]", (-1_500).pretty()), "[-1.5k]")]
+    #[case(format!("[{:+>7}]", 1_500.pretty()), "[+++1.5k]")]
+    #[case(format!("[{:08}]", (-1_500).pretty()), "[-0001.5k]")]
+    #[case(format!("[{:+08}]", 1_500.pretty()), "[+0001.5k]")]
+    fn format_specifiers_test(#[case] actual: String, #[case] expected: &str) {
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(format!("{:.2}", 23_520_123.pretty()), "23.52M")]
+    #[case(format!("{:.0}", 23_520_123.pretty()), "24M")]
+    #[case(format!("{:.3}", 7.pretty()), "7.000")]
+    #[case(format!("{:.2}", (0.1 + 0.2).pretty()), "0.30")]
+    #[case(format!("{:.2}", (-0.001).pretty()), "-0.00")]
+    #[case(format!("{:.1}", 999_960.pretty()), "1.0M")]
+    #[case(format!("{:.1}", 0.999_96.pretty()), "1.0")]
+    #[case(format!("{:.1}", PrettyFormatter::si().display(0.000_423_6)), "423.6µ")]
+    #[case(format!("{:.1}", PrettyFormatter::si().display(0.000_999_96)), "1.0m")]
+    #[case(format!("{:.2}", PrettyFormatter::bytes().display(1_536_000)), "1.54 MB")]
+    #[case(format!("{:.1}", PrettyFormatter::new().overflow(Overflow::Saturate).display(i128::MAX)), "999Qi+")]
+    #[case(format!("[{:>+9.2}]", 23_520_123.pretty()), "[  +23.52M]")]
+    fn precision_test(#[case] actual: String, #[case] expected: &str) {
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pretty_writes_into_buffer_test() {
+        let mut out = String::from("views: ");
+        write!(out, "{}, {}", 1_500.pretty(), (-0.37).pretty()).unwrap();
+
+        assert_eq!(out, "views: 1.5k, -0.37");
+    }
+
+    #[test]
+    fn display_error_test() {
+        let mut out = String::new();
+
+        assert!(write!(out, "{}", f64::NAN.pretty()).is_err());
+        assert!(write!(out, "{}", i128::MAX.pretty()).is_err());
+    }
+}
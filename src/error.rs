@@ -0,0 +1,174 @@
+use core::{error::Error, fmt};
+
+use crate::MAX_GROUP_SIZE;
+
+/// An error that can occur when formatting a number prettily.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrettyNumError {
+    /// The number's magnitude rounds to a value too large for the largest available suffix.
+    OutOfRange,
+    /// The number is NaN or infinite.
+    NonFinite,
+    /// The formatted number does not fit in the buffer passed to [`PrettyFormatter::format_into`](crate::PrettyFormatter::format_into).
+    BufferTooSmall,
+    /// The sink passed to [`PrettyFormatter::write_to`](crate::PrettyFormatter::write_to) returned an error.
+    WriteFailed,
+}
+
+impl fmt::Display for PrettyNumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrettyNumError::OutOfRange => {
+                write!(
+                    f,
+                    "number is too large to be formatted with the available suffixes"
+                )
+            }
+            PrettyNumError::NonFinite => write!(f, "number is NaN or infinite"),
+            PrettyNumError::BufferTooSmall => {
+                write!(f, "buffer is too small for the formatted number")
+            }
+            PrettyNumError::WriteFailed => write!(f, "formatted number could not be written"),
+        }
+    }
+}
+
+impl Error for PrettyNumError {}
+
+/// An error returned when creating an invalid [`Suffixes`](crate::Suffixes) table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SuffixError {
+    /// The table has no suffixes.
+    NoSuffixes,
+    /// The suffix at `index` is empty.
+    EmptySuffix {
+        /// The index of the suffix in the table.
+        index: usize,
+    },
+    /// The suffix at `index` is the same as an earlier suffix.
+    Duplicate {
+        /// The index of the suffix in the table.
+        index: usize,
+    },
+    /// The suffix at `index` contains a character that would make formatted numbers ambiguous.
+    InvalidCharacter {
+        /// The index of the suffix in the table.
+        index: usize,
+        /// The invalid character.
+        character: char,
+    },
+    /// A table of singular forms, exponents or thresholds does not have as many entries as the table it belongs to.
+    LengthMismatch {
+        /// The number of suffixes in the table.
+        expected: usize,
+        /// The number of singular forms, exponents or thresholds given.
+        found: usize,
+    },
+    /// The separator contains a character that would make formatted numbers ambiguous.
+    InvalidSeparator {
+        /// The invalid character.
+        character: char,
+    },
+    /// The group size is 0 or greater than [`MAX_GROUP_SIZE`](crate::MAX_GROUP_SIZE).
+    InvalidGroupSize {
+        /// The invalid group size.
+        group_size: u8,
+    },
+    /// The exponent at `index` is not greater than the previous one, or is more than [`MAX_GROUP_SIZE`](crate::MAX_GROUP_SIZE) above it.
+    InvalidExponent {
+        /// The index of the exponent in the table.
+        index: usize,
+    },
+    /// The threshold at `index` is below the power of its suffix, is not below the power of the next one,
+    /// or is more than [`MAX_GROUP_SIZE`](crate::MAX_GROUP_SIZE) above the power of the previous one.
+    InvalidThreshold {
+        /// The index of the threshold in the table.
+        index: usize,
+    },
+}
+
+impl fmt::Display for SuffixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuffixError::NoSuffixes => write!(f, "suffix table is empty"),
+            SuffixError::EmptySuffix { index } => write!(f, "suffix {index} is empty"),
+            SuffixError::Duplicate { index } => {
+                write!(f, "suffix {index} is the same as an earlier suffix")
+            }
+            SuffixError::InvalidCharacter { index, character } => {
+                write!(f, "suffix {index} contains invalid character {character:?}")
+            }
+            SuffixError::LengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "expected {expected} entries, one per suffix, but found {found}"
+                )
+            }
+            SuffixError::InvalidSeparator { character } => {
+                write!(f, "separator contains invalid character {character:?}")
+            }
+            SuffixError::InvalidGroupSize { group_size } => {
+                write!(
+                    f,
+                    "group size {group_size} is not between 1 and {MAX_GROUP_SIZE}"
+                )
+            }
+            SuffixError::InvalidExponent { index } => {
+                write!(
+                    f,
+                    "exponent {index} is not between 1 and {MAX_GROUP_SIZE} above the previous one"
+                )
+            }
+            SuffixError::InvalidThreshold { index } => {
+                write!(f, "threshold {index} is outside the range of its suffix")
+            }
+        }
+    }
+}
+
+impl Error for SuffixError {}
+
+/// An error returned when parsing a compact number such as `"23.5M"` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input is empty or only whitespace.
+    Empty,
+    /// The input does not start with a number, e.g. `"k"` or `"--5"`.
+    InvalidNumber,
+    /// The text after the number is not one of the parser's suffixes, e.g. `"1.5x"`.
+    UnknownSuffix,
+    /// The text after the number matches none of the suffixes exactly, but more than one of them when compared case-insensitively.
+    AmbiguousSuffix,
+    /// The number is not a whole number once scaled by its suffix, e.g. `"1.2345k"`.
+    Fractional,
+    /// The number is too large for the target integer type.
+    Overflow,
+    /// The input is a valid number, but not displayed the way the formatter would display it, e.g. `"1500"` or `"1.50k"` rather than `"1.5k"`.
+    Noncanonical,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a number from an empty string"),
+            ParseError::InvalidNumber => write!(f, "invalid number"),
+            ParseError::UnknownSuffix => write!(f, "unknown suffix"),
+            ParseError::AmbiguousSuffix => {
+                write!(f, "suffix matches more than one suffix regardless of case")
+            }
+            ParseError::Fractional => write!(f, "number is not a whole number"),
+            ParseError::Overflow => write!(f, "number is too large for the target type"),
+            ParseError::Noncanonical => {
+                write!(
+                    f,
+                    "number is not displayed the way the formatter displays it"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,1600 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::{
+    fmt::{self, Write},
+    ops::RangeInclusive,
+};
+
+#[cfg(feature = "std")]
+use crate::buffer::IoWriter;
+use crate::{
+    buffer::{Counter, Matcher, SliceWriter},
+    decimal::{Decimal, Rounding},
+    parse::to_signed,
+    Locale, ParseError, Pretty, PrettyNumError, PrettyNumber, PrettyParser, Suffixes,
+};
+
+/// The largest number of significant digits or decimals a [`PrettyFormatter`] can be configured with.
+pub const MAX_PRECISION: u8 = 30;
+
+/// What to do with a number too large to be formatted with the largest suffix (quintillions by default).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Return [`PrettyNumError::OutOfRange`].
+    #[default]
+    Error,
+    /// Display the largest formattable value followed by a `+`, e.g. `"999Qi+"` with the default suffixes or `"9999兆+"` when grouping by 10,000.
+    Saturate,
+    /// Fall back to scientific notation, e.g. `"1.2e21"`.
+    Scientific,
+}
+
+/// How to round numbers to the displayed precision.
+///
+/// Directed modes are applied to the signed number, so [`RoundingMode::Floor`] never over-reports a positive count
+/// and formats `-1_001` as `"-1.1k"`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties rounded away from zero: `1_250` is `"1.3k"`.
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, with ties rounded to an even last digit: `1_250` is `"1.2k"` and `1_350` is `"1.4k"`.
+    HalfEven,
+    /// Round toward negative infinity: `1_999` is `"1.9k"`.
+    Floor,
+    /// Round toward positive infinity: `1_001` is `"1.1k"`.
+    Ceil,
+    /// Round toward zero: `1_999` is `"1.9k"` and `-1_999` is `"-1.9k"`.
+    TowardZero,
+}
+
+/// A reusable configuration for formatting numbers prettily.
+///
+/// [`PrettyNumber::pretty_format`] is a shortcut for formatting with [`PrettyFormatter::new`].
+/// # Examples
+/// ```
+/// # use pretty_num::PrettyFormatter;
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// const FINANCE: PrettyFormatter = PrettyFormatter::new()
+///     .max_significant_digits(3)
+///     .max_decimals(2);
+///
+/// assert_eq!(FINANCE.format(1_234_567), String::from("1.23M"));
+/// assert_eq!(FINANCE.format(23_520_123), String::from("23.5M"));
+///
+/// // Trailing zeros are kept down to the minimum number of decimals.
+/// let padded = PrettyFormatter::new().min_decimals(1);
+/// assert_eq!(padded.format(2_000_000), String::from("2.0M"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrettyFormatter<'a> {
+    suffixes: Suffixes<'a>,
+    decimal_separator: char,
+    unit: &'a str,
+    max_significant_digits: u8,
+    max_decimals: u8,
+    min_decimals: u8,
+    rounding_mode: RoundingMode,
+    overflow: Overflow,
+    fixed_decimals: bool,
+}
+
+impl<'a> PrettyFormatter<'a> {
+    /// Creates a formatter with the default configuration: [`Suffixes::SHORT`], a `.` decimal separator, a maximum of 3 significant digits,
+    /// no more than one decimal point, [`RoundingMode::HalfUp`] and [`Overflow::Error`].
+    pub const fn new() -> Self {
+        PrettyFormatter {
+            suffixes: Suffixes::SHORT,
+            decimal_separator: '.',
+            unit: "",
+            max_significant_digits: 3,
+            max_decimals: 1,
+            min_decimals: 0,
+            rounding_mode: RoundingMode::HalfUp,
+            overflow: Overflow::Error,
+            fixed_decimals: false,
+        }
+    }
+
+    /// Creates a formatter for byte sizes with SI prefixes for successive powers of 1,000, e.g. "512 B", "2 MB" or "1.5 GB".
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let formatter = PrettyFormatter::bytes();
+    /// assert_eq!(formatter.format(512), String::from("512 B"));
+    /// assert_eq!(formatter.format(2_000_000), String::from("2 MB"));
+    /// # }
+    /// ```
+    pub const fn bytes() -> Self {
+        PrettyFormatter::new()
+            .suffixes(Suffixes::SI_BYTES)
+            .unit("B")
+    }
+
+    /// Creates a formatter for byte sizes with IEC binary prefixes for successive powers of 1,024, e.g. "512 B", "2 MiB" or "1.5 GiB".
+    ///
+    /// A size of 1,000 or more of a unit carries over into the next one, so 1,023 KiB is displayed as "1 MiB",
+    /// unless it is rounded toward zero, e.g. with [`RoundingMode::Floor`], which displays it as "1023 KiB" so that larger sizes never display as less.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let formatter = PrettyFormatter::binary_bytes();
+    /// assert_eq!(formatter.format(512), String::from("512 B"));
+    /// assert_eq!(formatter.format(1_610_612_736u64), String::from("1.5 GiB"));
+    /// # }
+    /// ```
+    pub const fn binary_bytes() -> Self {
+        PrettyFormatter::new()
+            .suffixes(Suffixes::IEC_BYTES)
+            .unit("B")
+    }
+
+    /// Creates a formatter for measurements with SI prefixes in both directions, from pico up to exa, e.g. "420µ" or "3.3k".
+    ///
+    /// Add a [unit](PrettyFormatter::unit) to display it after the prefix.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let seconds = PrettyFormatter::si().unit("s");
+    /// assert_eq!(seconds.format(0.00042), String::from("420µs"));
+    /// assert_eq!(seconds.format(1.5), String::from("1.5s"));
+    ///
+    /// let volts = PrettyFormatter::si().unit("V");
+    /// assert_eq!(volts.format(3_300), String::from("3.3kV"));
+    /// # }
+    /// ```
+    pub const fn si() -> Self {
+        PrettyFormatter::new().suffixes(Suffixes::SI)
+    }
+
+    /// Sets the suffixes used for successive powers of 1,000, or of the suffixes' own group size.
+    pub const fn suffixes(mut self, suffixes: Suffixes<'a>) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Sets the character separating the integer part of a number from its decimals.
+    /// # Panics
+    /// Panics if `separator` is an ASCII digit.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        assert!(
+            !separator.is_ascii_digit(),
+            "the decimal separator must not be a digit"
+        );
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets a unit appended after the suffix, or after the number when it has no suffix, e.g. `"B"` for "512 B" and "1.5 kB".
+    /// # Panics
+    /// Panics if `unit` contains an ASCII digit.
+    pub const fn unit(mut self, unit: &'a str) -> Self {
+        let bytes = unit.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(
+                !bytes[index].is_ascii_digit(),
+                "the unit must not contain a digit"
+            );
+            index += 1;
+        }
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the decimal separator and the short suffixes of a locale.
+    ///
+    /// Use [`suffixes`](PrettyFormatter::suffixes) afterwards with [`Locale::long`] for the locale's scale words.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{Locale, PrettyFormatter};
+    /// # #[cfg(all(feature = "alloc", feature = "de"))]
+    /// # {
+    /// let formatter = PrettyFormatter::new().locale(Locale::DE);
+    /// assert_eq!(formatter.format(23_520_123), String::from("23,5\u{a0}Mio."));
+    ///
+    /// let formatter = formatter.suffixes(Locale::DE.long());
+    /// assert_eq!(formatter.format(23_520_123), String::from("23,5 Millionen"));
+    ///
+    /// // German leaves thousands uncompacted, and they are not rounded.
+    /// assert_eq!(PrettyFormatter::new().locale(Locale::DE).format(123_456), String::from("123456"));
+    /// # }
+    /// ```
+    pub const fn locale(self, locale: Locale<'a>) -> Self {
+        self.decimal_separator(locale.decimal_separator())
+            .suffixes(locale.short())
+    }
+
+    /// Sets the maximum number of significant digits displayed. Numbers with more integer digits than this are rounded, e.g. `523k` becomes `"520k"` with 2 significant digits.
+    /// Numbers below the first suffix and numbers with more than 3 integer digits, which only suffixes spanning more than a thousand display,
+    /// are never rounded to fewer integer digits, e.g. German leaves 12,345 uncompacted as "12345" and Japanese displays 23,520,123 as "2352万".
+    ///
+    /// Numbers with a magnitude less than 1 are displayed with one significant digit less than this (but at least one), since the leading zero counts as a digit.
+    /// # Panics
+    /// Panics if `digits` is 0 or greater than [`MAX_PRECISION`].
+    pub const fn max_significant_digits(mut self, digits: u8) -> Self {
+        assert!(
+            digits > 0 && digits <= MAX_PRECISION,
+            "the maximum number of significant digits must be between 1 and MAX_PRECISION"
+        );
+        self.max_significant_digits = digits;
+        self
+    }
+
+    /// Sets the maximum number of decimals displayed for numbers with a magnitude of at least 1.
+    /// # Panics
+    /// Panics if `decimals` is greater than [`MAX_PRECISION`].
+    pub const fn max_decimals(mut self, decimals: u8) -> Self {
+        assert!(
+            decimals <= MAX_PRECISION,
+            "the maximum number of decimals must not be greater than MAX_PRECISION"
+        );
+        self.max_decimals = decimals;
+        self
+    }
+
+    /// Sets the minimum number of decimals displayed. Trailing zeros are only dropped down to this many decimals, and zeros are appended if fewer decimals were rounded to.
+    /// # Panics
+    /// Panics if `decimals` is greater than [`MAX_PRECISION`].
+    pub const fn min_decimals(mut self, decimals: u8) -> Self {
+        assert!(
+            decimals <= MAX_PRECISION,
+            "the minimum number of decimals must not be greater than MAX_PRECISION"
+        );
+        self.min_decimals = decimals;
+        self
+    }
+
+    /// Displays exactly `decimals` decimals whatever the magnitude of the number, like the precision of a float in `format!`,
+    /// for the precision of a [`Pretty`] number's format specifier.
+    pub(crate) const fn fixed_decimals(mut self, decimals: u8) -> Self {
+        self.max_significant_digits = MAX_PRECISION;
+        self.max_decimals = decimals;
+        self.min_decimals = decimals;
+        self.fixed_decimals = true;
+        self
+    }
+
+    /// Sets how numbers are rounded to the displayed precision.
+    pub const fn rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+
+    /// Sets what to do with numbers too large to be formatted with the largest suffix.
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Formats a number according to this configuration.
+    /// # Panics
+    /// Panics if [`try_format`](PrettyFormatter::try_format) returns an error.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, number: impl PrettyNumber) -> String {
+        self.try_format(number)
+            .unwrap_or_else(|error| panic!("Cannot format {number}: {error}"))
+    }
+
+    /// Formats a number according to this configuration, returning an error instead of panicking.
+    /// # Errors
+    /// Returns [`PrettyNumError::OutOfRange`] if the overflow policy is [`Overflow::Error`] and the number's magnitude rounds to one group of the largest suffix or more, e.g. 1,000 quintillions by default,
+    /// or [`PrettyNumError::NonFinite`] if the number is NaN or infinite.
+    #[cfg(feature = "alloc")]
+    pub fn try_format(&self, number: impl PrettyNumber) -> Result<String, PrettyNumError> {
+        self.round(number).map(|rounded| rounded.to_string())
+    }
+
+    /// Formats a number according to this configuration into a caller-provided buffer, returning the formatted part of it.
+    /// This works without `std` or `alloc`.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{PrettyFormatter, PrettyNumError};
+    /// let mut buffer = [0; 8];
+    /// assert_eq!(PrettyFormatter::new().format_into(23_520_123, &mut buffer), Ok("23.5M"));
+    ///
+    /// let mut buffer = [0; 4];
+    /// assert_eq!(PrettyFormatter::new().format_into(23_520_123, &mut buffer), Err(PrettyNumError::BufferTooSmall));
+    /// ```
+    /// # Errors
+    /// Returns [`PrettyNumError::BufferTooSmall`] if the formatted number does not fit in `buffer`,
+    /// or the same errors as [`try_format`](PrettyFormatter::try_format) otherwise.
+    pub fn format_into<'b>(
+        &self,
+        number: impl PrettyNumber,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b str, PrettyNumError> {
+        let rounded = self.round(number)?;
+        let mut writer = SliceWriter::new(buffer);
+        write!(writer, "{rounded}").map_err(|_| PrettyNumError::BufferTooSmall)?;
+        Ok(writer.into_str())
+    }
+
+    /// Writes a number formatted according to this configuration straight into a [`fmt::Write`] sink, returning the number of bytes written.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// let mut csv = String::from("views,");
+    /// assert_eq!(PrettyFormatter::new().write_to(23_520_123, &mut csv), Ok(5));
+    /// assert_eq!(csv, "views,23.5M");
+    /// ```
+    /// # Errors
+    /// Returns [`PrettyNumError::WriteFailed`] if the sink returns an error, which may leave part of the number written,
+    /// or the same errors as [`try_format`](PrettyFormatter::try_format) otherwise, in which case nothing is written.
+    pub fn write_to(
+        &self,
+        number: impl PrettyNumber,
+        out: &mut impl fmt::Write,
+    ) -> Result<usize, PrettyNumError> {
+        let rounded = self.round(number)?;
+        let mut counter = Counter::new(out);
+        write!(counter, "{rounded}").map_err(|_| PrettyNumError::WriteFailed)?;
+        Ok(counter.written())
+    }
+
+    /// Writes a number formatted according to this configuration straight into an [`io::Write`](std::io::Write) sink, returning the number of bytes written.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// let mut out = Vec::new();
+    /// assert_eq!(PrettyFormatter::bytes().write_io(1_500_000, &mut out).ok(), Some(6));
+    /// assert_eq!(out, b"1.5 MB");
+    /// ```
+    /// # Errors
+    /// Returns the sink's error if writing fails, or an error of kind [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+    /// wrapping the [`PrettyNumError`] that [`try_format`](PrettyFormatter::try_format) would return.
+    #[cfg(feature = "std")]
+    pub fn write_io(
+        &self,
+        number: impl PrettyNumber,
+        out: &mut impl std::io::Write,
+    ) -> std::io::Result<usize> {
+        use std::io;
+
+        let rounded = self
+            .round(number)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        let mut writer = IoWriter::new(out);
+        let mut counter = Counter::new(&mut writer);
+        match write!(counter, "{rounded}") {
+            Ok(()) => Ok(counter.written()),
+            Err(_) => Err(writer
+                .into_error()
+                .unwrap_or_else(|| io::Error::other("formatting the number failed"))),
+        }
+    }
+
+    /// Wraps a number so that it is formatted according to this configuration when displayed, without allocating a `String`.
+    ///
+    /// [`PrettyNumber::pretty`] is a shortcut for displaying with [`PrettyFormatter::new`].
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// let formatter = PrettyFormatter::new().max_decimals(2);
+    /// assert_eq!(format!("{} views", formatter.display(1_234_567)), "1.23M views");
+    /// ```
+    pub const fn display<N: PrettyNumber>(self, number: N) -> Pretty<'a, N> {
+        Pretty::new(number, self)
+    }
+
+    /// Creates a parser that understands this formatter's suffixes, separators and unit, to read formatted numbers back.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyFormatter;
+    /// let formatter = PrettyFormatter::bytes();
+    /// assert_eq!(formatter.parser().parse("1.5 MB"), Ok(1_500_000));
+    /// ```
+    pub const fn parser(&self) -> PrettyParser<'a> {
+        PrettyParser::new()
+            .suffixes(self.suffixes)
+            .decimal_separator(self.decimal_separator)
+            .unit(self.unit)
+    }
+
+    /// Returns the range of integers that are displayed exactly like `number`, since compact numbers are lossy.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{PrettyFormatter, RoundingMode};
+    /// let formatter = PrettyFormatter::new();
+    /// assert_eq!(formatter.range_of(23_520_123), Ok(23_450_000..=23_549_999));
+    /// assert_eq!(formatter.range_of(-1_500), Ok(-1_549..=-1_450));
+    ///
+    /// let floor = formatter.rounding_mode(RoundingMode::Floor);
+    /// assert_eq!(floor.range_of(23_520_123), Ok(23_500_000..=23_599_999));
+    /// ```
+    /// # Errors
+    /// Returns the same errors as [`try_format`](PrettyFormatter::try_format) for `number`.
+    pub fn range_of(&self, number: i64) -> Result<RangeInclusive<i64>, PrettyNumError> {
+        let target = self.round(number)?;
+        let displays_like_target = |n: i128| {
+            self.round(n as i64)
+                .is_ok_and(|rounded| rounded.displays_like(&target))
+        };
+
+        // Rounding is monotonic, binary sizes rounded toward zero included, so the numbers displayed like the target form a range around it that can be binary searched.
+        let (mut low, mut high) = (i64::MIN as i128, number as i128);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if displays_like_target(middle) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let start = low as i64;
+
+        let (mut low, mut high) = (number as i128, i64::MAX as i128);
+        while low < high {
+            let middle = low + (high - low + 1) / 2;
+            if displays_like_target(middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        Ok(start..=high as i64)
+    }
+
+    /// Returns the range of integers that this formatter displays as `formatted`, e.g. to show which exact values "23.5M" stands for.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{Overflow, ParseError, PrettyFormatter, Suffixes};
+    /// let formatter = PrettyFormatter::new();
+    /// assert_eq!(formatter.parse_range("23.5M"), Ok(23_450_000..=23_549_999));
+    /// assert_eq!(formatter.parse_range("999"), Ok(999..=999));
+    ///
+    /// // Binary prefixes rarely stand for a whole number.
+    /// let binary = PrettyFormatter::binary_bytes();
+    /// assert_eq!(binary.parse_range("99.2 KiB"), Ok(101_530..=101_631));
+    ///
+    /// // Text that the formatter would not display has no range.
+    /// assert_eq!(formatter.parse_range("1.50k"), Err(ParseError::Noncanonical));
+    ///
+    /// // A saturated number stands for every number beyond the largest one displayed.
+    /// let social = formatter.suffixes(Suffixes::SOCIAL).overflow(Overflow::Saturate);
+    /// assert_eq!(social.parse_range("999T+"), Ok(999_500_000_000_000..=i64::MAX));
+    /// ```
+    /// # Errors
+    /// Returns the same errors as [`PrettyParser::parse`] with this formatter's [parser](PrettyFormatter::parser), except that the value may be fractional,
+    /// or [`ParseError::Noncanonical`] if no integer is displayed exactly as `formatted`.
+    pub fn parse_range(&self, formatted: &str) -> Result<RangeInclusive<i64>, ParseError> {
+        let formatted = formatted.trim();
+        let (unsaturated, saturated) = match formatted.strip_suffix('+') {
+            Some(unsaturated) => (unsaturated, true),
+            None => (formatted, false),
+        };
+        let (negative, integer, fractional) = self.parser().parse_integer_part(unsaturated)?;
+        let displays_as_formatted = |number: i64| {
+            self.round(number).is_ok_and(|rounded| {
+                let mut matcher = Matcher::new(formatted);
+                write!(matcher, "{rounded}").is_ok() && matcher.is_match()
+            })
+        };
+
+        // The displayed value is rarely an integer, e.g. 101,580.8 for "99.2 KiB", but the range of integers displayed like it surrounds it,
+        // so it contains one of the integers on either side if it contains any. Values just beyond an `i64`, e.g. "8 EiB", are clamped to it.
+        // A saturated number, e.g. "999T+", is only displayed for the numbers up to the bound of an `i64`.
+        let bound = if negative { i64::MIN } else { i64::MAX };
+        let clamped = |magnitude: u128| to_signed(negative, magnitude).unwrap_or(bound);
+        let candidates = if saturated {
+            [Some(bound), None]
+        } else {
+            [
+                Some(clamped(integer)),
+                fractional.then(|| clamped(integer.saturating_add(1))),
+            ]
+        };
+        let number = candidates
+            .into_iter()
+            .flatten()
+            .find(|&number| displays_as_formatted(number))
+            .ok_or(match to_signed(negative, integer) {
+                Some(_) => ParseError::Noncanonical,
+                None => ParseError::Overflow,
+            })?;
+
+        self.range_of(number).map_err(|_| ParseError::Noncanonical)
+    }
+
+    /// Rounds a number to its displayed precision according to this configuration.
+    pub(crate) fn round(&self, number: impl PrettyNumber) -> Result<Rounded<'a>, PrettyNumError> {
+        let (negative, magnitude) = number.sign_and_magnitude()?;
+        self.round_decimal(negative, magnitude)
+    }
+
+    /// Rounds a number given its sign and magnitude, so every number type can share the same logic.
+    ///
+    /// All scaling and rounding is done with exact decimal arithmetic.
+    fn round_decimal(
+        &self,
+        negative: bool,
+        magnitude: Decimal,
+    ) -> Result<Rounded<'a>, PrettyNumError> {
+        if magnitude.is_zero() {
+            return Ok(self.rounded(false, 0, 0, ""));
+        }
+
+        let rounding = Rounding::new(self.rounding_mode, negative);
+        let suffixes = self.suffixes;
+        let leading_exponent = magnitude.leading_exponent();
+        let max_significant_digits = self.max_significant_digits as i32;
+
+        if leading_exponent < 0 {
+            let sub_units = suffixes.sub_units();
+            let last_power = -3 * sub_units.len() as i32;
+
+            // Numbers smaller than the last sub-unit keep one significant digit less, e.g. "0.37" or "0.0042".
+            if leading_exponent < last_power {
+                let decimals = if self.fixed_decimals {
+                    self.max_decimals as i32
+                } else {
+                    (max_significant_digits - 1).max(1) - 1 - (leading_exponent - last_power)
+                };
+                let rounded = magnitude.scale_rounded(1, last_power - decimals, rounding);
+                let suffix = sub_units.last().copied().unwrap_or("");
+                return Ok(self.rounded(negative, rounded, decimals as u32, suffix));
+            }
+
+            // Rounding up carries over into the previous sub-unit, and then into the unsuffixed level.
+            for level in (1..=(2 - leading_exponent) as usize / 3).rev() {
+                if let Some((rounded, decimals)) =
+                    self.round_to_level(magnitude, rounding, (1, -3 * level as i32), 1_000)
+                {
+                    return Ok(self.rounded(negative, rounded, decimals, sub_units[level - 1]));
+                }
+            }
+        }
+
+        let mut level = (1..=suffixes.len() + 1)
+            .rev()
+            .find(|&level| {
+                let (multiplier, power) = suffixes.start(level);
+                magnitude.at_least(multiplier, power)
+            })
+            .unwrap_or(0);
+        while level <= suffixes.len() {
+            let divisor = suffixes.divisor(level);
+            let limit = self.carry_limit(level, rounding);
+            let Some((rounded, decimals)) =
+                self.round_to_level(magnitude, rounding, divisor, limit)
+            else {
+                level += 1;
+                continue;
+            };
+
+            let suffix = if level == 0 {
+                ""
+            } else {
+                let divisor = 10u128.pow(decimals);
+                let has_visible_decimals = self.min_decimals > 0 || rounded % divisor != 0;
+                let singular = suffixes
+                    .plural_rule()
+                    .is_singular(rounded / divisor, has_visible_decimals);
+                suffixes.get(level - 1, singular)
+            };
+            return Ok(self.rounded(negative, rounded, decimals, suffix));
+        }
+
+        match self.overflow {
+            Overflow::Error => Err(PrettyNumError::OutOfRange),
+            Overflow::Saturate => {
+                let last = suffixes.len();
+                let nines = self.carry_limit(last, rounding) - 1;
+                let suffix = suffixes.get(last - 1, false);
+                Ok(Rounded {
+                    notation: Notation::Saturated,
+                    ..self.rounded(negative, nines, 0, suffix)
+                })
+            }
+            Overflow::Scientific => Ok(self.round_scientific(negative, magnitude, rounding)),
+        }
+    }
+
+    /// Returns how many units of a scale level round up to the next one, e.g. 1,000 for "1000k" to be displayed as "1M".
+    fn carry_limit(&self, level: usize, rounding: Rounding) -> u128 {
+        // Rounding toward zero cannot reach 1,024 units, and carrying over at 1,000 would display 1,000 KiB as "0.9 MiB", less than "999 KiB",
+        // so binary levels keep up to 1,023 units instead.
+        if self.suffixes.is_binary() && rounding == Rounding::TowardZero {
+            1_024
+        } else {
+            10u128.pow(self.suffixes.step_digits(level) as u32)
+        }
+    }
+
+    /// Rounds a magnitude to the displayed precision in units of `multiplier * 10^power`, returning the rounded value and its number of decimals,
+    /// or `None` if it rounds up to `limit` units and carries over into the next level.
+    fn round_to_level(
+        &self,
+        magnitude: Decimal,
+        rounding: Rounding,
+        (multiplier, power): (u128, i32),
+        limit: u128,
+    ) -> Option<(u128, u32)> {
+        let integer_digits = magnitude.integer_digits(multiplier, power);
+        let decimals =
+            (self.max_significant_digits as i32 - integer_digits).min(self.max_decimals as i32);
+        // Nothing would show that an unsuffixed number was rounded, so its integer digits are kept, e.g. German "12345",
+        // and so are those of suffixes spanning more than a thousand, e.g. Japanese "2352万".
+        let decimals = if (multiplier, power) == (1, 0) || integer_digits > 3 {
+            decimals.max(0)
+        } else {
+            decimals
+        };
+        let rounded = magnitude.scale_rounded(multiplier, power - decimals, rounding);
+
+        let (rounded, decimals) = if decimals < 0 {
+            (rounded * 10u128.pow(decimals.unsigned_abs()), 0)
+        } else {
+            (rounded, decimals as u32)
+        };
+
+        // Rounding up to the next level carries over into its suffix, e.g. 999,950 is "1M" rather than "1000k".
+        (rounded < limit * 10u128.pow(decimals)).then_some((rounded, decimals))
+    }
+
+    /// Rounds a magnitude for scientific notation with the same rounding rules as the suffixed format, e.g. `"1.2e21"`.
+    fn round_scientific(
+        &self,
+        negative: bool,
+        magnitude: Decimal,
+        rounding: Rounding,
+    ) -> Rounded<'a> {
+        let mut exponent = magnitude.leading_exponent();
+        let decimals = (self.max_significant_digits - 1).min(self.max_decimals) as u32;
+        let mut rounded = magnitude.scale_rounded(1, exponent - decimals as i32, rounding);
+
+        if rounded == 10u128.pow(decimals + 1) {
+            rounded /= 10;
+            exponent += 1;
+        }
+
+        Rounded {
+            notation: Notation::Scientific(exponent),
+            ..self.rounded(negative, rounded, decimals, "")
+        }
+    }
+
+    /// Creates a suffixed number of `value / 10^decimals` with this configuration.
+    fn rounded(&self, negative: bool, value: u128, decimals: u32, suffix: &'a str) -> Rounded<'a> {
+        Rounded {
+            negative,
+            value,
+            decimals,
+            suffix,
+            notation: Notation::Suffixed,
+            decimal_separator: self.decimal_separator,
+            min_decimals: self.min_decimals as u32,
+            separator: self.suffixes.separator(),
+            unit: self.unit,
+        }
+    }
+}
+
+/// A number rounded to its displayed precision, which is written out without allocating.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rounded<'a> {
+    negative: bool,
+    value: u128,
+    decimals: u32,
+    suffix: &'a str,
+    notation: Notation,
+    decimal_separator: char,
+    min_decimals: u32,
+    separator: &'a str,
+    unit: &'a str,
+}
+
+/// How a [`Rounded`] number is written around its digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Suffixed,
+    /// The largest formattable value followed by a `+`, without padded decimals.
+    Saturated,
+    Scientific(i32),
+}
+
+impl Rounded<'_> {
+    /// Returns whether the number is displayed with a minus sign.
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns whether two numbers rounded with the same configuration are displayed identically, without writing them out.
+    pub(crate) fn displays_like(&self, other: &Rounded) -> bool {
+        (self.negative, self.trimmed(), self.suffix, self.notation)
+            == (
+                other.negative,
+                other.trimmed(),
+                other.suffix,
+                other.notation,
+            )
+    }
+
+    /// Returns the minimum number of decimals displayed, which is 0 for a saturated number.
+    fn min_decimals(&self) -> u32 {
+        match self.notation {
+            Notation::Saturated => 0,
+            Notation::Suffixed | Notation::Scientific(_) => self.min_decimals,
+        }
+    }
+
+    /// Returns the value and number of decimals without the trailing zeros that are not displayed,
+    /// dropping them from the decimals down to the minimum number of decimals so that a trailing ".0" is never displayed by default.
+    fn trimmed(&self) -> (u128, u32) {
+        let (mut value, mut decimals) = (self.value, self.decimals);
+
+        while decimals > self.min_decimals() && value % 10 == 0 {
+            value /= 10;
+            decimals -= 1;
+        }
+
+        (value, decimals)
+    }
+
+    /// Writes `value / 10^decimals` with its trailing zeros trimmed, padded with zeros to the minimum number of decimals.
+    fn write_fixed(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let min_decimals = self.min_decimals();
+        let (value, decimals) = self.trimmed();
+
+        // Numbers far smaller than one can have more decimals than fit in a `u128`, so leading zeros are written separately.
+        let digits = value.checked_ilog10().unwrap_or(0) + 1;
+        if decimals < digits {
+            let divisor = 10u128.pow(decimals);
+            write!(f, "{}", value / divisor)?;
+            if decimals > 0 {
+                f.write_char(self.decimal_separator)?;
+                write!(f, "{:0>width$}", value % divisor, width = decimals as usize)?;
+            }
+        } else {
+            f.write_char('0')?;
+            f.write_char(self.decimal_separator)?;
+            write_zeros(f, decimals - digits)?;
+            write!(f, "{value}")?;
+        }
+
+        if decimals < min_decimals {
+            if decimals == 0 {
+                f.write_char(self.decimal_separator)?;
+            }
+            write_zeros(f, min_decimals - decimals)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the number without its sign, followed by its suffix and unit.
+    pub(crate) fn write_unsigned(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        self.write_fixed(f)?;
+        if let Notation::Scientific(exponent) = self.notation {
+            write!(f, "e{exponent}")?;
+        }
+
+        // The separator is only written when something follows it.
+        if !self.suffix.is_empty() || !self.unit.is_empty() {
+            f.write_str(self.separator)?;
+            f.write_str(self.suffix)?;
+            f.write_str(self.unit)?;
+        }
+
+        if let Notation::Saturated = self.notation {
+            f.write_char('+')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Rounded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+
+        self.write_unsigned(f)
+    }
+}
+
+/// Writes `count` zeros.
+fn write_zeros(f: &mut impl fmt::Write, count: u32) -> fmt::Result {
+    (0..count).try_for_each(|_| f.write_char('0'))
+}
+
+impl Default for PrettyFormatter<'_> {
+    fn default() -> Self {
+        PrettyFormatter::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Overflow, PrettyFormatter, RoundingMode};
+    use crate::{ParseError, PrettyNumError, StackString, Suffixes};
+    use rstest::rstest;
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(PrettyFormatter::new(), 1_234_567, "1.2M")]
+    #[case(PrettyFormatter::new().max_decimals(2), 1_234_567, "1.23M")]
+    #[case(PrettyFormatter::new().max_decimals(2), 12_345_678, "12.3M")]
+    #[case(PrettyFormatter::new().max_decimals(2), 1_204_567, "1.2M")]
+    #[case(PrettyFormatter::new().max_decimals(2), 999_996, "1M")]
+    #[case(PrettyFormatter::new().max_significant_digits(4).max_decimals(2), 12_345_678, "12.35M")]
+    #[case(PrettyFormatter::new().max_significant_digits(4).max_decimals(2), 123_456_789, "123.5M")]
+    #[case(PrettyFormatter::new().max_significant_digits(4).max_decimals(2), 999_960, "1M")]
+    #[case(PrettyFormatter::new().max_significant_digits(4).max_decimals(2), 1_234, "1.23k")]
+    #[case(PrettyFormatter::new().max_significant_digits(2), 523_000, "520k")]
+    #[case(PrettyFormatter::new().max_significant_digits(2), 525_000, "530k")]
+    #[case(PrettyFormatter::new().max_significant_digits(2), 995_000, "1M")]
+    #[case(PrettyFormatter::new().max_significant_digits(2), 1_250, "1.3k")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 1_250, "1k")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 96_000, "100k")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 960_000, "1M")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 523, "523")]
+    #[case(PrettyFormatter::new().max_decimals(0), 1_960, "2k")]
+    #[case(PrettyFormatter::new().max_decimals(0), 1_450, "1k")]
+    #[case(PrettyFormatter::new().min_decimals(1), 2_000_000, "2.0M")]
+    #[case(PrettyFormatter::new().min_decimals(1), 523_000, "523.0k")]
+    #[case(PrettyFormatter::new().min_decimals(2), 23_520_123, "23.50M")]
+    #[case(PrettyFormatter::new().min_decimals(2), 7, "7.00")]
+    #[case(PrettyFormatter::new().min_decimals(2), 0, "0.00")]
+    #[case(PrettyFormatter::new().max_decimals(2).min_decimals(2), -1_204_567, "-1.20M")]
+    fn format_integer_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: i64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(PrettyFormatter::new(), 0.3712, "0.37")]
+    #[case(PrettyFormatter::new().max_significant_digits(4), 0.3712, "0.371")]
+    #[case(PrettyFormatter::new().max_significant_digits(2), 0.3712, "0.4")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 0.3712, "0.4")]
+    #[case(PrettyFormatter::new().max_decimals(0), 0.3712, "0.37")]
+    #[case(PrettyFormatter::new().max_decimals(2), 12.345, "12.3")]
+    #[case(PrettyFormatter::new().max_significant_digits(4).max_decimals(2), 12.345, "12.35")]
+    #[case(PrettyFormatter::new().min_decimals(1), 0.5, "0.5")]
+    #[case(PrettyFormatter::new().min_decimals(3), 0.5, "0.500")]
+    fn format_float_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: f64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(PrettyFormatter::new().overflow(Overflow::Scientific), "1.2e21")]
+    #[case(PrettyFormatter::new().overflow(Overflow::Scientific).max_decimals(2), "1.23e21")]
+    #[case(PrettyFormatter::new().overflow(Overflow::Scientific).max_significant_digits(1), "1e21")]
+    #[case(PrettyFormatter::new().overflow(Overflow::Saturate), "999Qi+")]
+    fn format_overflow_test(#[case] formatter: PrettyFormatter, #[case] expected: &str) {
+        assert_eq!(
+            formatter.format(1_234_000_000_000_000_000_000i128).as_str(),
+            expected
+        );
+    }
+
+    const ROUNDING_INPUTS: [i128; 9] = [
+        1_250, 1_350, 1_999, 1_001, 999_500, 999_001, -1_250, -1_999, -1_001,
+    ];
+
+    // Expected outputs for `ROUNDING_INPUTS` in thousandths of a suffix, where `{0}` is the suffix and `{1}` the next one.
+    #[rstest]
+    #[case(
+        RoundingMode::HalfUp,
+        ["1.3{0}", "1.4{0}", "2{0}", "1{0}", "1{1}", "999{0}", "-1.3{0}", "-2{0}", "-1{0}"]
+    )]
+    #[case(
+        RoundingMode::HalfEven,
+        ["1.2{0}", "1.4{0}", "2{0}", "1{0}", "1{1}", "999{0}", "-1.2{0}", "-2{0}", "-1{0}"]
+    )]
+    #[case(
+        RoundingMode::Floor,
+        ["1.2{0}", "1.3{0}", "1.9{0}", "1{0}", "999{0}", "999{0}", "-1.3{0}", "-2{0}", "-1.1{0}"]
+    )]
+    #[case(
+        RoundingMode::Ceil,
+        ["1.3{0}", "1.4{0}", "2{0}", "1.1{0}", "1{1}", "1{1}", "-1.2{0}", "-1.9{0}", "-1{0}"]
+    )]
+    #[case(
+        RoundingMode::TowardZero,
+        ["1.2{0}", "1.3{0}", "1.9{0}", "1{0}", "999{0}", "999{0}", "-1.2{0}", "-1.9{0}", "-1{0}"]
+    )]
+    fn rounding_mode_test(#[case] rounding_mode: RoundingMode, #[case] expected: [&str; 9]) {
+        let formatter = PrettyFormatter::new()
+            .rounding_mode(rounding_mode)
+            .overflow(Overflow::Saturate);
+        let suffixes = ["k", "M", "B", "T", "Qa", "Qi", "Qi+"];
+
+        for (index, suffix) in suffixes[..6].iter().enumerate() {
+            // The inputs are in thousandths of the suffix, and the "k" suffix is a thousand.
+            let unit = 1_000i128.pow(index as u32);
+
+            for (input, expected) in ROUNDING_INPUTS.into_iter().zip(expected) {
+                let expected = expected
+                    .replace("{0}", suffix)
+                    .replace("1{1}", &format!("1{}", suffixes[index + 1]))
+                    .replace("1Qi+", "999Qi+");
+
+                let mut formatted = StackString::<8>::new();
+                formatter.write_to(input * unit, &mut formatted).unwrap();
+
+                assert_eq!(
+                    formatted,
+                    expected.as_str(),
+                    "{input} thousandths of {suffix}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(RoundingMode::Floor, 0.379, "0.37")]
+    #[case(RoundingMode::Floor, -0.371, "-0.38")]
+    #[case(RoundingMode::Ceil, 0.371, "0.38")]
+    #[case(RoundingMode::TowardZero, -0.379, "-0.37")]
+    #[case(RoundingMode::HalfEven, 0.125, "0.12")]
+    #[case(RoundingMode::HalfEven, 12.25, "12.2")]
+    #[case(RoundingMode::HalfUp, 12.25, "12.3")]
+    #[case(RoundingMode::Floor, 999.9, "999")]
+    #[case(RoundingMode::Ceil, 999.1, "1k")]
+    fn rounding_mode_unsuffixed_test(
+        #[case] rounding_mode: RoundingMode,
+        #[case] input: f64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::new().rounding_mode(rounding_mode);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(RoundingMode::Floor, "1.2e21")]
+    #[case(RoundingMode::Ceil, "1.3e21")]
+    fn rounding_mode_scientific_test(#[case] rounding_mode: RoundingMode, #[case] expected: &str) {
+        let formatter = PrettyFormatter::new()
+            .rounding_mode(rounding_mode)
+            .overflow(Overflow::Scientific);
+
+        assert_eq!(
+            formatter.format(1_201_000_000_000_000_000_000u128).as_str(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(Suffixes::SOCIAL, 23_520_123, "23.5M")]
+    #[case(Suffixes::SOCIAL, 999_500_000_000_000, "999T+")]
+    #[case(Suffixes::UPPERCASE, 1_500, "1.5K")]
+    #[case(Suffixes::UPPERCASE, -999_950, "-1M")]
+    #[case(Suffixes::FINANCE, 23_520_123, "23.5MM")]
+    #[case(Suffixes::FINANCE, 7_667_973_223, "7.7B")]
+    #[case(Suffixes::new(&["K", "Mil", "Bn"]).unwrap(), 23_520_123, "23.5Mil")]
+    #[case(Suffixes::new(&["K", "Mil", "Bn"]).unwrap(), 999_999_999, "1Bn")]
+    #[case(Suffixes::new(&["K", "Mil", "Bn"]).unwrap(), 1_000_000_000_000, "999Bn+")]
+    #[case(Suffixes::new(&["thou"]).unwrap(), 523, "523")]
+    #[case(Suffixes::new(&["thou"]).unwrap(), 523_400, "523thou")]
+    fn format_with_suffixes_test(
+        #[case] suffixes: Suffixes,
+        #[case] input: i64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::new()
+            .suffixes(suffixes)
+            .overflow(Overflow::Saturate);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(23_520_123, "23.5 million")]
+    #[case(3_000, "3 thousand")]
+    #[case(-1_000_000, "-1 million")]
+    #[case(999_950, "1 million")]
+    #[case(523, "523")]
+    #[case(i64::MAX as i128, "9.2 quintillion")]
+    #[case(1_000_000_000_000_000_000_000, "999 quintillion+")]
+    fn format_long_form_test(#[case] input: i128, #[case] expected: &str) {
+        let formatter = PrettyFormatter::new()
+            .suffixes(Suffixes::LONG)
+            .overflow(Overflow::Saturate);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(PrettyFormatter::new(), 1_000_000, "1 Million")]
+    #[case(PrettyFormatter::new(), 999_960, "1 Million")]
+    #[case(PrettyFormatter::new(), 1_040_000, "1 Million")]
+    #[case(PrettyFormatter::new(), 1_050_000, "1.1 Millionen")]
+    #[case(PrettyFormatter::new(), -2_000_000, "-2 Millionen")]
+    #[case(PrettyFormatter::new(), 1_000, "1 Tausend")]
+    #[case(PrettyFormatter::new().min_decimals(1), 1_000_000, "1.0 Millionen")]
+    #[case(PrettyFormatter::new().max_significant_digits(1), 1_400_000, "1 Million")]
+    fn format_singular_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: i64,
+        #[case] expected: &str,
+    ) {
+        let suffixes = Suffixes::new(&["Tausend", "Millionen", "Milliarden"])
+            .and_then(|s| s.with_singular(&["Tausend", "Million", "Milliarde"]))
+            .and_then(|s| s.with_separator(" "))
+            .unwrap();
+
+        assert_eq!(
+            formatter.suffixes(suffixes).format(input).as_str(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(',', 23_520_123.0, "23,5M")]
+    #[case('·', 0.37, "0·37")]
+    #[case(',', 1_000.0, "1k")]
+    fn format_with_decimal_separator_test(
+        #[case] separator: char,
+        #[case] input: f64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::new().decimal_separator(separator);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(4, 9_999, "9999")]
+    #[case(4, 23_520_123, "2352k")]
+    #[case(4, 99_995_000, "1M")]
+    #[case(4, 123_456_789_012, "1235M")]
+    #[case(2, 1_234, "12.3k")]
+    #[case(2, 5, "5")]
+    #[case(2, 999_999, "1B")]
+    fn format_group_size_test(#[case] group_size: u8, #[case] input: u64, #[case] expected: &str) {
+        let suffixes = Suffixes::new(&["k", "M", "B"])
+            .and_then(|s| s.with_group_size(group_size))
+            .unwrap();
+        let formatter = PrettyFormatter::new()
+            .suffixes(suffixes)
+            .max_significant_digits(4);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(23_520_123, 2, "2.35 Cr")]
+    #[case(23_520_123, 1, "2.4 Cr")]
+    #[case(150_000, 1, "1.5 L")]
+    #[case(1_234, 1, "1.2 K")]
+    #[case(99_999, 1, "1 L")]
+    #[case(9_999_999, 2, "1 Cr")]
+    #[case(5_000_000_000, 1, "500 Cr")]
+    #[case(12_345_000_000, 1, "1235 Cr")]
+    #[case(-1_500_000_000_000, 1, "-1.5 L Cr")]
+    #[case(999, 1, "999")]
+    fn format_indian_test(#[case] input: i64, #[case] max_decimals: u8, #[case] expected: &str) {
+        let formatter = PrettyFormatter::new()
+            .suffixes(Suffixes::INDIAN)
+            .max_decimals(max_decimals);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999_999, "1 M")]
+    #[case(1_000_000_000, "1000 M")]
+    #[case(7_700_000_000, "7700 M")]
+    #[case(9_940_000_000, "9940 M")]
+    #[case(9_999_950_000, "10 mil M")]
+    #[case(12_345_678_901, "12.3 mil M")]
+    #[case(1_000_000_000_000, "1 B")]
+    fn thresholds_test(#[case] input: i64, #[case] expected: &str) {
+        let suffixes = Suffixes::new(&["mil", "M", "mil M", "B"])
+            .and_then(|s| s.with_separator(" "))
+            .and_then(|s| s.with_thresholds(&[3, 6, 10, 12]))
+            .unwrap();
+        let formatter = PrettyFormatter::new().suffixes(suffixes);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+        assert_eq!(
+            formatter
+                .parse_range(expected)
+                .map(|range| range.contains(&input)),
+            Ok(true)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999_400_000, "99.9 Cr")]
+    #[case(999_500_000, "99 Cr+")]
+    fn ladder_overflow_test(#[case] input: u64, #[case] expected: &str) {
+        let suffixes = Suffixes::new(&["K", "L", "Cr"])
+            .and_then(|s| s.with_exponents(&[3, 5, 7]))
+            .and_then(|s| s.with_separator(" "))
+            .unwrap();
+        let formatter = PrettyFormatter::new()
+            .suffixes(suffixes)
+            .overflow(Overflow::Saturate);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0, "0 B", "0 B")]
+    #[case(1, "1 B", "1 B")]
+    #[case(512, "512 B", "512 B")]
+    #[case(999, "999 B", "999 B")]
+    #[case(1_000, "1 kB", "1 KiB")]
+    #[case(1_023, "1 kB", "1 KiB")]
+    #[case(1_024, "1 kB", "1 KiB")]
+    #[case(1_536, "1.5 kB", "1.5 KiB")]
+    #[case(999_999, "1 MB", "977 KiB")]
+    #[case(1_048_575, "1 MB", "1 MiB")]
+    #[case(2_000_000, "2 MB", "1.9 MiB")]
+    #[case(10_485_760, "10.5 MB", "10 MiB")]
+    #[case(1_610_612_736, "1.6 GB", "1.5 GiB")]
+    #[case(u64::MAX, "18.4 EB", "16 EiB")]
+    fn format_bytes_test(
+        #[case] input: u64,
+        #[case] expected_si: &str,
+        #[case] expected_iec: &str,
+    ) {
+        assert_eq!(PrettyFormatter::bytes().format(input).as_str(), expected_si);
+        assert_eq!(
+            PrettyFormatter::binary_bytes().format(input).as_str(),
+            expected_iec
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0.5, "0.5 B")]
+    #[case(-1_536.0, "-1.5 KiB")]
+    #[case(1_536.4, "1.5 KiB")]
+    #[case(1.5 * 1_024.0 * 1_024.0 * 1_024.0 * 1_024.0, "1.5 TiB")]
+    fn format_binary_bytes_float_test(#[case] input: f64, #[case] expected: &str) {
+        assert_eq!(
+            PrettyFormatter::binary_bytes().format(input).as_str(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(RoundingMode::HalfUp, 1_048_575, "1 MiB")]
+    #[case(RoundingMode::HalfUp, 1_024_000, "1 MiB")]
+    #[case(RoundingMode::Floor, 1_048_575, "1023 KiB")]
+    #[case(RoundingMode::Floor, 1_024_000, "1000 KiB")]
+    #[case(RoundingMode::Floor, 1_023_999, "999 KiB")]
+    #[case(RoundingMode::Floor, 1_048_576, "1 MiB")]
+    #[case(RoundingMode::Floor, 1_023, "1023 B")]
+    #[case(RoundingMode::Floor, -1_048_575, "-1 MiB")]
+    #[case(RoundingMode::TowardZero, -1_048_575, "-1023 KiB")]
+    #[case(RoundingMode::Ceil, -1_024_000, "-1000 KiB")]
+    #[case(RoundingMode::Ceil, 1_024_000, "1 MiB")]
+    #[case(RoundingMode::Floor, 1_638, "1.5 KiB")]
+    #[case(RoundingMode::Ceil, 1_537, "1.6 KiB")]
+    #[case(RoundingMode::HalfEven, 1_280, "1.2 KiB")]
+    #[case(RoundingMode::HalfUp, 1_280, "1.3 KiB")]
+    fn binary_bytes_rounding_test(
+        #[case] rounding_mode: RoundingMode,
+        #[case] input: i64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::binary_bytes().rounding_mode(rounding_mode);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[rstest]
+    fn binary_bytes_monotonic_test(
+        #[values(
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero
+        )]
+        rounding_mode: RoundingMode,
+    ) {
+        let formatter = PrettyFormatter::binary_bytes().rounding_mode(rounding_mode);
+        // The integer part of the displayed value is read back, since binary sizes are rarely whole numbers.
+        let read_back = |number: i64| {
+            let mut buffer = [0; 16];
+            let formatted = formatter.format_into(number, &mut buffer).unwrap();
+            let (negative, integer, _) = formatter.parser().parse_integer_part(formatted).unwrap();
+            if negative {
+                -(integer as i128)
+            } else {
+                integer as i128
+            }
+        };
+
+        // Sizes from 900 to 1,100 of each unit, in eighths of it, straddle the carry over into the next unit.
+        for level in 0..6 {
+            let unit = 1i128 << (10 * level);
+            let eighths = (-8_800..-7_200).chain(7_200..8_800);
+            let displayed: Vec<_> = eighths.map(|n| read_back((n * unit / 8) as i64)).collect();
+
+            for pair in displayed.windows(2) {
+                assert!(
+                    pair[0] <= pair[1],
+                    "{pair:?} of level {level} are out of order"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(RoundingMode::HalfUp, Overflow::Error, Err(PrettyNumError::OutOfRange))]
+    #[case(RoundingMode::HalfUp, Overflow::Saturate, Ok(String::from("999 EiB+")))]
+    #[case(RoundingMode::Floor, Overflow::Saturate, Ok(String::from("1023 EiB+")))]
+    #[case(
+        RoundingMode::HalfUp,
+        Overflow::Scientific,
+        Ok(String::from("1.2e21 B"))
+    )]
+    fn binary_bytes_overflow_test(
+        #[case] rounding_mode: RoundingMode,
+        #[case] overflow: Overflow,
+        #[case] expected: Result<String, PrettyNumError>,
+    ) {
+        let formatter = PrettyFormatter::binary_bytes()
+            .rounding_mode(rounding_mode)
+            .overflow(overflow);
+
+        assert_eq!(formatter.try_format(1u128 << 70), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0.00042, "420µs")]
+    #[case(0.5, "500ms")]
+    #[case(0.001, "1ms")]
+    #[case(0.0000042, "4.2µs")]
+    #[case(-0.000_000_123_4, "-123ns")]
+    #[case(4e-12, "4ps")]
+    #[case(4e-15, "0.004ps")]
+    #[case(0.000_999_96, "1ms")]
+    #[case(0.999_96, "1s")]
+    #[case(1.5, "1.5s")]
+    #[case(3_300.0, "3.3ks")]
+    #[case(2.5e18, "2.5Es")]
+    fn format_si_test(#[case] input: f64, #[case] expected: &str) {
+        assert_eq!(
+            PrettyFormatter::si().unit("s").format(input).as_str(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(RoundingMode::Floor, 0.000_999_96, "999µ")]
+    #[case(RoundingMode::Ceil, 0.000_420_1, "421µ")]
+    #[case(RoundingMode::Floor, -0.000_420_1, "-421µ")]
+    fn si_rounding_test(
+        #[case] rounding_mode: RoundingMode,
+        #[case] input: f64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::si().rounding_mode(rounding_mode);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sub_units_without_si_test() {
+        let suffixes = Suffixes::SHORT.with_sub_units(&["m"]).unwrap();
+        let formatter = PrettyFormatter::new().suffixes(suffixes);
+
+        assert_eq!(formatter.format(0.25).as_str(), "250m");
+        assert_eq!(formatter.format(0.00025).as_str(), "0.25m");
+    }
+
+    #[rstest]
+    #[case(PrettyFormatter::new(), 23_520_123.0, 8, Ok("23.5M"))]
+    #[case(PrettyFormatter::new(), 23_520_123.0, 5, Ok("23.5M"))]
+    #[case(
+        PrettyFormatter::new(),
+        23_520_123.0,
+        4,
+        Err(PrettyNumError::BufferTooSmall)
+    )]
+    #[case(PrettyFormatter::si().unit("s"), 0.00042, 6, Ok("420µs"))]
+    #[case(PrettyFormatter::si().unit("s"), 0.00042, 5, Err(PrettyNumError::BufferTooSmall))]
+    #[case(PrettyFormatter::new(), f64::NAN, 8, Err(PrettyNumError::NonFinite))]
+    #[case(PrettyFormatter::new(), 1e30, 64, Err(PrettyNumError::OutOfRange))]
+    fn format_into_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: f64,
+        #[case] capacity: usize,
+        #[case] expected: Result<&str, PrettyNumError>,
+    ) {
+        let mut buffer = [0; 64];
+
+        assert_eq!(
+            formatter.format_into(input, &mut buffer[..capacity]),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(PrettyFormatter::new(), 23_520_123.0, Ok(5), "row: 23.5M")]
+    #[case(PrettyFormatter::si().unit("s"), 0.00042, Ok(6), "row: 420µs")]
+    #[case(
+        PrettyFormatter::new(),
+        f64::NAN,
+        Err(PrettyNumError::NonFinite),
+        "row: "
+    )]
+    #[case(PrettyFormatter::new(), 1e30, Err(PrettyNumError::OutOfRange), "row: ")]
+    fn write_to_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: f64,
+        #[case] expected: Result<usize, PrettyNumError>,
+        #[case] expected_output: &str,
+    ) {
+        let mut out = String::from("row: ");
+
+        assert_eq!(formatter.write_to(input, &mut out), expected);
+        assert_eq!(out, expected_output);
+    }
+
+    #[test]
+    fn write_to_failing_sink_test() {
+        let mut out = StackString::<2>::new();
+
+        assert_eq!(
+            PrettyFormatter::new().write_to(23_520_123, &mut out),
+            Err(PrettyNumError::WriteFailed)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest]
+    #[case(23_520_123.0, Some(5), "23.5M")]
+    #[case(1e30, None, "")]
+    fn write_io_test(
+        #[case] input: f64,
+        #[case] expected: Option<usize>,
+        #[case] expected_output: &str,
+    ) {
+        let mut out = Vec::new();
+        let result = PrettyFormatter::new().write_io(input, &mut out);
+
+        assert_eq!(result.as_ref().ok(), expected.as_ref());
+        assert_eq!(out, expected_output.as_bytes());
+        if let Err(error) = result {
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+            assert_eq!(
+                error.into_inner().map(|e| e.to_string()),
+                Some(PrettyNumError::OutOfRange.to_string())
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_io_failing_sink_test() {
+        let mut buffer = [0; 2];
+        let error = PrettyFormatter::new()
+            .write_io(23_520_123, &mut &mut buffer[..])
+            .unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(PrettyFormatter::new(), 0, 0..=0)]
+    #[case(PrettyFormatter::new(), 999, 999..=999)]
+    #[case(PrettyFormatter::new(), 1_000, 1_000..=1_049)]
+    #[case(PrettyFormatter::new(), 1_000_000, 999_500..=1_049_999)]
+    #[case(PrettyFormatter::new(), 23_520_123, 23_450_000..=23_549_999)]
+    #[case(PrettyFormatter::new(), -23_520_123, -23_549_999..=-23_450_000)]
+    #[case(PrettyFormatter::new(), i64::MAX, 9_150_000_000_000_000_000..=i64::MAX)]
+    #[case(PrettyFormatter::new(), i64::MIN, i64::MIN..=-9_150_000_000_000_000_000)]
+    #[case(PrettyFormatter::new().max_decimals(2), 1_234_567, 1_225_000..=1_234_999)]
+    #[case(PrettyFormatter::new().min_decimals(1), 2_000_000, 1_950_000..=2_049_999)]
+    #[case(PrettyFormatter::new().rounding_mode(RoundingMode::Floor), -1_001, -1_100..=-1_001)]
+    #[case(PrettyFormatter::new().rounding_mode(RoundingMode::Ceil), 1_001, 1_001..=1_100)]
+    #[case(PrettyFormatter::new().rounding_mode(RoundingMode::HalfEven), 1_250, 1_150..=1_250)]
+    #[case(PrettyFormatter::binary_bytes(), 1_536, 1_485..=1_587)]
+    #[case(PrettyFormatter::binary_bytes().rounding_mode(RoundingMode::Floor), 1_023_999, 1_022_976..=1_023_999)]
+    #[case(PrettyFormatter::binary_bytes().rounding_mode(RoundingMode::Floor), 1_024_000, 1_024_000..=1_025_023)]
+    #[case(PrettyFormatter::binary_bytes().rounding_mode(RoundingMode::Floor), 1_048_575, 1_047_552..=1_048_575)]
+    #[case(PrettyFormatter::binary_bytes().rounding_mode(RoundingMode::Floor), 1_638, 1_536..=1_638)]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::SOCIAL).overflow(Overflow::Saturate), 5_000_000_000_000_000, 999_500_000_000_000..=i64::MAX)]
+    fn range_of_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: i64,
+        #[case] expected: std::ops::RangeInclusive<i64>,
+    ) {
+        assert_eq!(formatter.range_of(input), Ok(expected.clone()));
+        for bound in [*expected.start(), *expected.end()] {
+            assert_eq!(formatter.format(bound), formatter.format(input));
+        }
+    }
+
+    #[test]
+    fn range_of_out_of_range_test() {
+        let formatter = PrettyFormatter::new().suffixes(Suffixes::SOCIAL);
+
+        assert_eq!(
+            formatter.range_of(i64::MAX),
+            Err(PrettyNumError::OutOfRange)
+        );
+    }
+
+    #[rstest]
+    #[case(PrettyFormatter::new(), "1.5k", Ok(1_450..=1_549))]
+    #[case(PrettyFormatter::new(), " 23.5M ", Ok(23_450_000..=23_549_999))]
+    #[case(PrettyFormatter::new(), "-2M", Ok(-2_049_999..=-1_950_000))]
+    #[case(PrettyFormatter::new(), "1.50k", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new(), "1500", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new(), "2 M", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new(), "1000k", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new(), "1.5x", Err(ParseError::UnknownSuffix))]
+    #[case(PrettyFormatter::new().min_decimals(1), "1.50k", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new().min_decimals(1), "2.0M", Ok(1_950_000..=2_049_999))]
+    #[case(PrettyFormatter::bytes(), "1.5 kB", Ok(1_450..=1_549))]
+    #[case(PrettyFormatter::binary_bytes(), "99.2 KiB", Ok(101_530..=101_631))]
+    #[case(PrettyFormatter::binary_bytes(), "1.1 KiB", Ok(1_076..=1_177))]
+    #[case(PrettyFormatter::binary_bytes(), "-1.1 KiB", Ok(-1_177..=-1_076))]
+    #[case(PrettyFormatter::binary_bytes(), "1.5 KiB", Ok(1_485..=1_587))]
+    #[case(
+        PrettyFormatter::binary_bytes(),
+        "1.10 KiB",
+        Err(ParseError::Noncanonical)
+    )]
+    #[case(
+        PrettyFormatter::binary_bytes(),
+        "1.12 KiB",
+        Err(ParseError::Noncanonical)
+    )]
+    #[case(PrettyFormatter::si(), "420m", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::LONG), "1 million", Ok(999_500..=1_049_999))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::SOCIAL).overflow(Overflow::Saturate), "999T+", Ok(999_500_000_000_000..=i64::MAX))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::SOCIAL).overflow(Overflow::Saturate), "-999T+", Ok(i64::MIN..=-999_500_000_000_000))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::SOCIAL).overflow(Overflow::Saturate), "998T+", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::SOCIAL), "999T+", Err(ParseError::Noncanonical))]
+    #[case(PrettyFormatter::new().overflow(Overflow::Saturate), "999Qi+", Err(ParseError::Overflow))]
+    #[case(PrettyFormatter::binary_bytes().rounding_mode(RoundingMode::Floor), "1000 KiB", Ok(1_024_000..=1_025_023))]
+    fn parse_range_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: &str,
+        #[case] expected: Result<std::ops::RangeInclusive<i64>, ParseError>,
+    ) {
+        assert_eq!(formatter.parse_range(input), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unit_test() {
+        let formatter = PrettyFormatter::new().unit("/s");
+
+        assert_eq!(formatter.format(1_500).as_str(), "1.5k/s");
+        assert_eq!(formatter.format(15).as_str(), "15/s");
+    }
+
+    #[test]
+    #[should_panic]
+    fn digit_unit_should_panic() {
+        let _ = PrettyFormatter::new().unit("m2");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(Overflow::Error, Err(PrettyNumError::OutOfRange))]
+    #[case(Overflow::Saturate, Ok(String::from("9999B+")))]
+    #[case(Overflow::Scientific, Ok(String::from("1e16")))]
+    fn group_size_overflow_test(
+        #[case] overflow: Overflow,
+        #[case] expected: Result<String, PrettyNumError>,
+    ) {
+        let suffixes = Suffixes::new(&["k", "M", "B"])
+            .and_then(|s| s.with_group_size(4))
+            .unwrap();
+        let formatter = PrettyFormatter::new().suffixes(suffixes).overflow(overflow);
+
+        assert_eq!(formatter.try_format(9_999_500_000_000_000u64), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digit_decimal_separator_should_panic() {
+        let _ = PrettyFormatter::new().decimal_separator('0');
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn long_and_short_forms_agree_test() {
+        let short = PrettyFormatter::new();
+        let long = PrettyFormatter::new().suffixes(Suffixes::LONG);
+
+        for number in (0..200_000i64).map(|n| n * n * 7_919) {
+            let short_formatted = short.format(number);
+            let long_formatted = long.format(number);
+            let (short_number, short_suffix) = short_formatted
+                .split_at(short_formatted.trim_end_matches(char::is_alphabetic).len());
+            let (long_number, long_suffix) = long_formatted
+                .split_once(' ')
+                .unwrap_or((&long_formatted, ""));
+            let suffix_index =
+                |suffixes: Suffixes, suffix| suffixes.as_slice().iter().position(|&s| s == suffix);
+
+            assert_eq!(short_number, long_number);
+            assert_eq!(
+                suffix_index(Suffixes::SHORT, short_suffix),
+                suffix_index(Suffixes::LONG, long_suffix)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn format_with_long_suffix_table_test() {
+        let suffixes = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"];
+        let formatter = PrettyFormatter::new().suffixes(Suffixes::new(&suffixes).unwrap());
+
+        assert_eq!(formatter.format(u128::MAX).as_str(), "340l");
+        assert_eq!(formatter.format(1.5e30).as_str(), "1.5j");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formatter_is_reusable_test() {
+        let formatter = PrettyFormatter::new().max_decimals(2);
+        let formatted: Vec<_> = [1_234, 56_789, 987_654_321]
+            .into_iter()
+            .map(|n| formatter.format(n))
+            .collect();
+
+        assert_eq!(formatted, ["1.23k", "56.8k", "988M"]);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(31)]
+    #[should_panic]
+    fn invalid_significant_digits_should_panic(#[case] digits: u8) {
+        let _ = PrettyFormatter::new().max_significant_digits(digits);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_max_decimals_should_panic() {
+        let _ = PrettyFormatter::new().max_decimals(31);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_min_decimals_should_panic() {
+        let _ = PrettyFormatter::new().min_decimals(31);
+    }
+}
@@ -1,79 +1,266 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 //! This crate formats numbers in a compact form similar to that used on social media sites:
 //! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
 //! use pretty_num::PrettyNumber;
-//! 
+//!
 //! assert_eq!(23_520_123.pretty_format(), String::from("23.5M"));
+//! # }
 //! ```
+//!
+//! The crate supports `no_std`. Disable the default `std` feature, and enable `alloc` for the methods that return a `String`.
+//! Without `alloc`, numbers can still be written into any [`core::fmt::Write`] with [`PrettyNumber::pretty`],
+//! into a [`StackString`], or into a byte slice with [`PrettyFormatter::format_into`].
 
-const SUFFIXES: [char; 4] = ['k', 'M', 'B', 'T'];
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod buffer;
+mod decimal;
+mod display;
+mod error;
+mod formatter;
+mod locale;
+mod parse;
+mod suffixes;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+pub use buffer::StackString;
+use decimal::Decimal;
+pub use display::Pretty;
+pub use error::{ParseError, PrettyNumError, SuffixError};
+pub use formatter::{Overflow, PrettyFormatter, RoundingMode, MAX_PRECISION};
+pub use locale::Locale;
+pub use parse::{parse_pretty, PrettyInt, PrettyParser};
+pub use suffixes::{PluralRule, Suffixes, MAX_GROUP_SIZE};
+
+mod private {
+    use crate::{Decimal, PrettyNumError};
+
+    /// Converts a number into its sign and exact magnitude.
+    ///
+    /// This trait is private so that only the primitive number types can implement [`PrettyNumber`](crate::PrettyNumber).
+    pub trait Sealed: Copy + core::fmt::Display {
+        fn sign_and_magnitude(self) -> Result<(bool, Decimal), PrettyNumError>;
+    }
+}
 
 /// A number that can be formatted prettily.
-pub trait PrettyNumber {
-    /// Formats an integer to be more compact. The resulting string will have a maximum of 3 significant digits with no more than one decimal point.
+///
+/// The methods of this trait use the default [`PrettyFormatter`]; use a configured formatter for different precision.
+pub trait PrettyNumber: private::Sealed {
+    /// Formats a number to be more compact. The resulting string will have a maximum of 3 significant digits with no more than one decimal point.
+    /// Numbers with a magnitude less than 1 instead keep two significant digits.
+    ///
+    /// Rounding is exact and rounds half away from zero, so `1_450` becomes `"1.5k"`.
     /// # Examples
     /// ```
     /// # use pretty_num::PrettyNumber;
     /// // Integers with a magnitude less than 1,000 do not get compacted.
     /// assert_eq!(534.pretty_format(), String::from("534"));
-    /// 
+    ///
     /// // Integers with a magnitude greater than or equal to 1,000 get compacted.
     /// assert_eq!(15_000.pretty_format(), String::from("15k"));
-    /// 
+    ///
     /// // Integers will have a single decimal point when rounded.
     /// assert_eq!(4_230_542.pretty_format(), String::from("4.2M"));
-    /// 
+    ///
     /// // Formatted numbers get rounded to a number without a decimal place when appropriate.
     /// assert_eq!(5_031.pretty_format(), String::from("5k"));
-    /// 
+    ///
     /// // Also works with negative numbers.
     /// assert_eq!((-25_621_783).pretty_format(), String::from("-25.6M"));
-    /// 
-    /// // Can go as high as trillions!
+    ///
+    /// // Can go as high as quintillions!
     /// assert_eq!(36_777_121_590_100i64.pretty_format(), String::from("36.8T"));
+    /// assert_eq!(4_500_000_000_000_000i64.pretty_format(), String::from("4.5Qa"));
+    /// assert_eq!(i64::MAX.pretty_format(), String::from("9.2Qi"));
+    ///
+    /// // Works with every primitive integer type.
+    /// assert_eq!(18_446_744_073_709u64.pretty_format(), String::from("18.4T"));
+    /// assert_eq!(250usize.pretty_format(), String::from("250"));
+    ///
+    /// // Floats are formatted in the same style, without floating point noise.
+    /// assert_eq!(1234.56.pretty_format(), String::from("1.2k"));
+    /// assert_eq!(12.5f32.pretty_format(), String::from("12.5"));
+    /// assert_eq!(0.37.pretty_format(), String::from("0.37"));
+    /// assert_eq!((0.1 + 0.2).pretty_format(), String::from("0.3"));
     /// ```
     /// # Panics
-    /// This function panics if it is passed a number greater than 1 quadrillion or less than negative 1 quadrillion.
-    fn pretty_format(self) -> String;
-}
-
-impl<N: Into<i64>> PrettyNumber for N {
+    /// This function panics if it is passed NaN, an infinity, or a number whose magnitude rounds to 1 sextillion or more.
+    /// Use [`try_pretty_format`](PrettyNumber::try_pretty_format) to handle this case without panicking.
+    #[cfg(feature = "alloc")]
     fn pretty_format(self) -> String {
-        let number: i64 = self.into();
+        PrettyFormatter::new().format(self)
+    }
+
+    /// Formats a number to be more compact like [`pretty_format`](PrettyNumber::pretty_format), but returns an error instead of panicking.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{PrettyNumber, PrettyNumError};
+    /// assert_eq!(4_230_542.try_pretty_format(), Ok(String::from("4.2M")));
+    ///
+    /// // Numbers too large for the available suffixes produce an error.
+    /// assert_eq!(
+    ///     1_000_000_000_000_000_000_000i128.try_pretty_format(),
+    ///     Err(PrettyNumError::OutOfRange)
+    /// );
+    ///
+    /// // NaN and infinities produce an error.
+    /// assert_eq!(f64::NAN.try_pretty_format(), Err(PrettyNumError::NonFinite));
+    /// ```
+    /// # Errors
+    /// Returns [`PrettyNumError::OutOfRange`] if the number's magnitude rounds to 1 sextillion or more,
+    /// or [`PrettyNumError::NonFinite`] if the number is NaN or infinite.
+    #[cfg(feature = "alloc")]
+    fn try_pretty_format(self) -> Result<String, PrettyNumError> {
+        PrettyFormatter::new().try_format(self)
+    }
+
+    /// Formats a number to be more compact like [`try_pretty_format`](PrettyNumber::try_pretty_format), handling numbers too large for the largest suffix as specified by `overflow`.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::{Overflow, PrettyNumber, PrettyNumError};
+    /// let huge = 1_234_000_000_000_000_000_000i128;
+    ///
+    /// assert_eq!(huge.try_pretty_format_with(Overflow::Error), Err(PrettyNumError::OutOfRange));
+    /// assert_eq!(huge.try_pretty_format_with(Overflow::Saturate), Ok(String::from("999Qi+")));
+    /// assert_eq!(huge.try_pretty_format_with(Overflow::Scientific), Ok(String::from("1.2e21")));
+    ///
+    /// // Numbers within range are not affected.
+    /// assert_eq!(5_031.try_pretty_format_with(Overflow::Scientific), Ok(String::from("5k")));
+    /// ```
+    /// # Errors
+    /// Returns [`PrettyNumError::OutOfRange`] if `overflow` is [`Overflow::Error`] and the number's magnitude rounds to 1 sextillion or more,
+    /// or [`PrettyNumError::NonFinite`] if the number is NaN or infinite.
+    #[cfg(feature = "alloc")]
+    fn try_pretty_format_with(self, overflow: Overflow) -> Result<String, PrettyNumError> {
+        PrettyFormatter::new().overflow(overflow).try_format(self)
+    }
 
-        if number.abs() < 1000 {
-            number.to_string()
+    /// Wraps a number so that it is formatted like [`pretty_format`](PrettyNumber::pretty_format) when displayed,
+    /// writing directly into the output without allocating a `String`.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyNumber;
+    /// assert_eq!(format!("{} views", 23_520_123.pretty()), "23.5M views");
+    /// ```
+    fn pretty(self) -> Pretty<'static, Self> {
+        PrettyFormatter::new().display(self)
+    }
+
+    /// Writes a number formatted like [`pretty_format`](PrettyNumber::pretty_format) straight into a [`fmt::Write`](core::fmt::Write) sink,
+    /// returning the number of bytes written.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyNumber;
+    /// let mut row = String::from("<td>");
+    /// assert_eq!(23_520_123.pretty_write_to(&mut row), Ok(5));
+    /// row.push_str("</td>");
+    /// assert_eq!(row, "<td>23.5M</td>");
+    /// ```
+    /// # Errors
+    /// Returns [`PrettyNumError::WriteFailed`] if the sink returns an error, or the same errors as [`try_pretty_format`](PrettyNumber::try_pretty_format) otherwise.
+    fn pretty_write_to(self, out: &mut impl core::fmt::Write) -> Result<usize, PrettyNumError> {
+        PrettyFormatter::new().write_to(self, out)
+    }
+
+    /// Writes a number formatted like [`pretty_format`](PrettyNumber::pretty_format) straight into an [`io::Write`](std::io::Write) sink,
+    /// returning the number of bytes written.
+    /// # Examples
+    /// ```
+    /// # use pretty_num::PrettyNumber;
+    /// let mut csv = Vec::new();
+    /// assert_eq!(23_520_123.pretty_write_io(&mut csv).ok(), Some(5));
+    /// assert_eq!(csv, b"23.5M");
+    /// ```
+    /// # Errors
+    /// Returns the sink's error if writing fails, or an error of kind [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+    /// wrapping the [`PrettyNumError`] that [`try_pretty_format`](PrettyNumber::try_pretty_format) would return.
+    #[cfg(feature = "std")]
+    fn pretty_write_io(self, out: &mut impl std::io::Write) -> std::io::Result<usize> {
+        PrettyFormatter::new().write_io(self, out)
+    }
+}
+
+macro_rules! impl_pretty_number {
+    (unsigned: $($t:ty),*) => {$(
+        impl_pretty_number!(@impl $t, |n: $t| Ok((false, Decimal::from_integer(n as u128))));
+    )*};
+    (signed: $($t:ty),*) => {$(
+        // `unsigned_abs` cannot overflow, unlike `abs`, so `MIN` of every signed type is handled.
+        impl_pretty_number!(@impl $t, |n: $t| Ok((n < 0, Decimal::from_integer(n.unsigned_abs() as u128))));
+    )*};
+    (float: $($t:ty),*) => {$(
+        impl_pretty_number!(@impl $t, |n: $t| if n.is_finite() {
+            Ok((n.is_sign_negative(), Decimal::from_float(n)))
         } else {
-            let sign: i8 = if number < 0 { -1 } else { 1 };
-            let mut number_as_float = number.abs() as f32;
-            for suffix in SUFFIXES {
-                number_as_float /= 1000f32;
-
-                if number_as_float < 1000f32 {
-                    return format!(
-                        "{:.*}{suffix}",
-                        if (number_as_float - number_as_float.floor()) < 0.1
-                            || number_as_float >= 100f32
-                        {
-                            0
-                        } else {
-                            1
-                        },
-                        sign as f32 * number_as_float
-                    );
-                }
+            Err(PrettyNumError::NonFinite)
+        });
+    )*};
+    (@impl $t:ty, $sign_and_magnitude:expr) => {
+        impl private::Sealed for $t {
+            fn sign_and_magnitude(self) -> Result<(bool, Decimal), PrettyNumError> {
+                $sign_and_magnitude(self)
             }
-
-            panic!("Number {number} is larger than 1 quadrillion!");
         }
-    }
+
+        impl PrettyNumber for $t {}
+    };
 }
 
+impl_pretty_number!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_pretty_number!(signed: i8, i16, i32, i64, i128, isize);
+impl_pretty_number!(float: f32, f64);
+
 #[cfg(test)]
 mod test {
-    use crate::PrettyNumber;
+    use crate::{
+        parse_pretty, Overflow, PrettyFormatter, PrettyNumError, PrettyNumber, RoundingMode,
+        StackString,
+    };
+    use core::fmt::Write;
+    use proptest::prelude::*;
     use rstest::rstest;
 
+    #[rstest]
+    #[case(717, "717")]
+    #[case(1_500, "1.5k")]
+    #[case(-25_621_783, "-25.6M")]
+    #[case(999_950, "1M")]
+    #[case(i64::MAX, "9.2Qi")]
+    #[case(i64::MIN, "-9.2Qi")]
+    fn pretty_into_stack_string_test(#[case] input: i64, #[case] expected: &str) {
+        let mut formatted = StackString::<8>::new();
+        write!(formatted, "{}", input.pretty()).unwrap();
+
+        assert_eq!(formatted, expected);
+    }
+
+    #[rstest]
+    #[case(1_500, Ok(4))]
+    #[case(i128::MAX, Err(PrettyNumError::OutOfRange))]
+    #[case(-1_234_000_000_000_000_000_000, Err(PrettyNumError::OutOfRange))]
+    fn pretty_write_to_test(#[case] input: i128, #[case] expected: Result<usize, PrettyNumError>) {
+        let mut formatted = StackString::<8>::new();
+
+        assert_eq!(input.pretty_write_to(&mut formatted), expected);
+    }
+
+    #[test]
+    fn pretty_write_to_full_buffer_test() {
+        let mut formatted = StackString::<4>::new();
+
+        assert_eq!(
+            23_520_123.pretty_write_to(&mut formatted),
+            Err(PrettyNumError::WriteFailed)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
     #[rstest]
     #[case(7, "7")]
     #[case(42, "42")]
@@ -109,7 +296,7 @@ mod test {
     #[case(7_667_973_223, "7.7B")]
     #[case(-4_002_154_900, "-4B")]
     #[case(-6_534_664_725, "-6.5B")]
-    #[case(87_050_671_768, "87B")]
+    #[case(87_050_671_768, "87.1B")]
     #[case(44_444_333_222, "44.4B")]
     #[case(-32_010_345_093, "-32B")]
     #[case(-65_420_132_543, "-65.4B")]
@@ -133,11 +320,460 @@ mod test {
         assert_eq!(input.pretty_format().as_str(), expected);
     }
 
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999, "999")]
+    #[case(-999, "-999")]
+    #[case(1_000, "1k")]
+    #[case(-1_000, "-1k")]
+    #[case(1_099, "1.1k")]
+    #[case(-1_099, "-1.1k")]
+    #[case(1_100, "1.1k")]
+    #[case(-1_100, "-1.1k")]
+    #[case(1_449, "1.4k")]
+    #[case(-1_449, "-1.4k")]
+    #[case(1_450, "1.5k")]
+    #[case(-1_450, "-1.5k")]
+    #[case(100_000, "100k")]
+    #[case(-100_000, "-100k")]
+    #[case(100_499, "100k")]
+    #[case(-100_499, "-100k")]
+    #[case(100_500, "101k")]
+    #[case(-100_500, "-101k")]
+    #[case(999_000, "999k")]
+    #[case(-999_000, "-999k")]
+    #[case(999_499, "999k")]
+    #[case(-999_499, "-999k")]
+    #[case(1_000_000, "1M")]
+    #[case(-1_000_000, "-1M")]
+    #[case(1_099_999, "1.1M")]
+    #[case(-1_099_999, "-1.1M")]
+    #[case(1_100_000, "1.1M")]
+    #[case(-1_100_000, "-1.1M")]
+    #[case(1_449_999, "1.4M")]
+    #[case(-1_449_999, "-1.4M")]
+    #[case(1_450_000, "1.5M")]
+    #[case(-1_450_000, "-1.5M")]
+    #[case(100_000_000, "100M")]
+    #[case(-100_000_000, "-100M")]
+    #[case(100_499_999, "100M")]
+    #[case(-100_499_999, "-100M")]
+    #[case(100_500_000, "101M")]
+    #[case(-100_500_000, "-101M")]
+    #[case(999_000_000, "999M")]
+    #[case(-999_000_000, "-999M")]
+    #[case(999_499_999, "999M")]
+    #[case(-999_499_999, "-999M")]
+    #[case(1_000_000_000, "1B")]
+    #[case(-1_000_000_000, "-1B")]
+    #[case(1_099_999_999, "1.1B")]
+    #[case(-1_099_999_999, "-1.1B")]
+    #[case(1_100_000_000, "1.1B")]
+    #[case(-1_100_000_000, "-1.1B")]
+    #[case(1_449_999_999, "1.4B")]
+    #[case(-1_449_999_999, "-1.4B")]
+    #[case(1_450_000_000, "1.5B")]
+    #[case(-1_450_000_000, "-1.5B")]
+    #[case(100_000_000_000, "100B")]
+    #[case(-100_000_000_000, "-100B")]
+    #[case(100_499_999_999, "100B")]
+    #[case(-100_499_999_999, "-100B")]
+    #[case(100_500_000_000, "101B")]
+    #[case(-100_500_000_000, "-101B")]
+    #[case(999_000_000_000, "999B")]
+    #[case(-999_000_000_000, "-999B")]
+    #[case(999_499_999_999, "999B")]
+    #[case(-999_499_999_999, "-999B")]
+    #[case(1_000_000_000_000, "1T")]
+    #[case(-1_000_000_000_000, "-1T")]
+    #[case(1_099_999_999_999, "1.1T")]
+    #[case(-1_099_999_999_999, "-1.1T")]
+    #[case(1_100_000_000_000, "1.1T")]
+    #[case(-1_100_000_000_000, "-1.1T")]
+    #[case(1_449_999_999_999, "1.4T")]
+    #[case(-1_449_999_999_999, "-1.4T")]
+    #[case(1_450_000_000_000, "1.5T")]
+    #[case(-1_450_000_000_000, "-1.5T")]
+    #[case(100_000_000_000_000, "100T")]
+    #[case(-100_000_000_000_000, "-100T")]
+    #[case(100_499_999_999_999, "100T")]
+    #[case(-100_499_999_999_999, "-100T")]
+    #[case(100_500_000_000_000, "101T")]
+    #[case(-100_500_000_000_000, "-101T")]
+    #[case(999_000_000_000_000, "999T")]
+    #[case(-999_000_000_000_000, "-999T")]
+    #[case(999_499_999_999_999, "999T")]
+    #[case(-999_499_999_999_999, "-999T")]
+    #[case(7_949_999_999_999, "7.9T")]
+    #[case(1_000_049_999_999, "1T")]
+    #[case(16_777_217, "16.8M")]
+    fn pretty_format_boundary_test(#[case] input: i64, #[case] expected: &str) {
+        assert_eq!(input.pretty_format().as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999_499, "999k")]
+    #[case(999_500, "1M")]
+    #[case(999_950, "1M")]
+    #[case(999_999, "1M")]
+    #[case(-999_500, "-1M")]
+    #[case(999_499_999, "999M")]
+    #[case(999_500_000, "1B")]
+    #[case(999_600_000, "1B")]
+    #[case(-999_999_999, "-1B")]
+    #[case(999_499_999_999, "999B")]
+    #[case(999_500_000_000, "1T")]
+    #[case(999_950_000_000, "1T")]
+    #[case(-999_999_999_999, "-1T")]
+    #[case(999_499_999_999_999, "999T")]
+    #[case(-999_499_999_999_999, "-999T")]
+    #[case(1_949, "1.9k")]
+    #[case(1_950, "2k")]
+    #[case(1_960, "2k")]
+    #[case(9_949, "9.9k")]
+    #[case(9_960, "10k")]
+    #[case(99_949, "99.9k")]
+    #[case(99_960, "100k")]
+    #[case(-9_960_000, "-10M")]
+    #[case(19_960_000_000, "20B")]
+    #[case(-99_950_000_000_000, "-100T")]
+    fn pretty_format_carry_test(#[case] input: i64, #[case] expected: &str) {
+        assert_eq!(input.pretty_format().as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pretty_format_never_ends_in_zero_decimal_test() {
+        for scale in [1, 1_000, 1_000_000, 1_000_000_000] {
+            let numbers = (0..1_000_000i64).map(|n| n * scale);
+
+            for number in numbers.take_while(|&n| n < 999_500_000_000_000) {
+                for formatted in [number.pretty_format(), (-number).pretty_format()] {
+                    let digits = formatted.trim_end_matches(|c: char| c.is_alphabetic());
+
+                    assert!(
+                        !digits.ends_with(".0"),
+                        "{number} was formatted as {formatted}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(200u8.pretty_format(), "200")]
+    #[case(65_535u16.pretty_format(), "65.5k")]
+    #[case(4_294_967_295u32.pretty_format(), "4.3B")]
+    #[case(999_999_999_999u64.pretty_format(), "1T")]
+    #[case(u64::MAX.pretty_format(), "18.4Qi")]
+    #[case(123_456_789_012_345u64.pretty_format(), "123T")]
+    #[case(45_678_123_456_789u128.pretty_format(), "45.7T")]
+    #[case(7_500_000usize.pretty_format(), "7.5M")]
+    #[case((-128i8).pretty_format(), "-128")]
+    #[case((-32_768i16).pretty_format(), "-32.8k")]
+    #[case((-2_147_483_648i32).pretty_format(), "-2.1B")]
+    #[case((-98_765_432_109_876i128).pretty_format(), "-98.8T")]
+    #[case((-7_500_000isize).pretty_format(), "-7.5M")]
+    fn pretty_format_integer_types_test(#[case] actual: String, #[case] expected: &str) {
+        assert_eq!(actual.as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(1_000_000_000_000_000, "1Qa")]
+    #[case(-1_000_000_000_000_000, "-1Qa")]
+    #[case(1_099_999_999_999_999, "1.1Qa")]
+    #[case(-45_049_999_999_999_999, "-45Qa")]
+    #[case(999_499_999_999_999_999, "999Qa")]
+    #[case(999_500_000_000_000_000, "1Qi")]
+    #[case(-999_999_999_999_999_999, "-1Qi")]
+    #[case(1_000_000_000_000_000_000, "1Qi")]
+    #[case(i64::MAX, "9.2Qi")]
+    #[case(i64::MIN + 1, "-9.2Qi")]
+    fn pretty_format_quadrillion_and_quintillion_test(#[case] input: i64, #[case] expected: &str) {
+        assert_eq!(input.pretty_format().as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0.0, "0")]
+    #[case(-0.0, "0")]
+    #[case(0.37, "0.37")]
+    #[case(-0.37, "-0.37")]
+    #[case(0.3712, "0.37")]
+    #[case(0.375, "0.38")]
+    #[case(0.0042, "0.0042")]
+    #[case(0.1 + 0.2, "0.3")]
+    #[case(0.996, "1")]
+    #[case(0.994, "0.99")]
+    #[case(1.0, "1")]
+    #[case(1.25, "1.3")]
+    #[case(1.04, "1")]
+    #[case(12.5, "12.5")]
+    #[case(-12.96, "-13")]
+    #[case(123.4, "123")]
+    #[case(999.4, "999")]
+    #[case(999.5, "1k")]
+    #[case(1234.56, "1.2k")]
+    #[case(-1_960.0, "-2k")]
+    #[case(23_520_123.7, "23.5M")]
+    #[case(9.2e18, "9.2Qi")]
+    fn pretty_format_f64_test(#[case] input: f64, #[case] expected: &str) {
+        assert_eq!(input.pretty_format().as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pretty_format_smallest_f64_test() {
+        let expected = format!("0.{}5", "0".repeat(323));
+
+        assert_eq!(5e-324.pretty_format(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0.1, "0.1")]
+    #[case(0.37, "0.37")]
+    #[case(16_777_217.0, "16.8M")]
+    #[case(-1234.56, "-1.2k")]
+    #[case(f32::MAX, "999Qi+")]
+    fn pretty_format_f32_test(#[case] input: f32, #[case] expected: &str) {
+        assert_eq!(
+            input
+                .try_pretty_format_with(Overflow::Saturate)
+                .unwrap_or_default()
+                .as_str(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
     #[rstest]
-    #[case(1_000_000_000_000_000)]
-    #[case(-1_000_000_000_000_000)]
+    #[case(f64::NAN)]
+    #[case(f64::INFINITY)]
+    #[case(f64::NEG_INFINITY)]
+    fn try_pretty_format_non_finite_test(#[case] input: f64) {
+        assert_eq!(input.try_pretty_format(), Err(PrettyNumError::NonFinite));
+        assert_eq!(
+            (input as f32).try_pretty_format(),
+            Err(PrettyNumError::NonFinite)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(1e21, Overflow::Saturate, "999Qi+")]
+    #[case(-1.5e300, Overflow::Scientific, "-1.5e300")]
+    #[case(f64::MAX, Overflow::Scientific, "1.8e308")]
+    #[case(9.96e307, Overflow::Scientific, "1e308")]
+    fn try_pretty_format_f64_overflow_test(
+        #[case] input: f64,
+        #[case] overflow: Overflow,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            input.try_pretty_format_with(overflow).as_deref(),
+            Ok(expected)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(i8::MIN.pretty_format(), "-128")]
+    #[case(i8::MAX.pretty_format(), "127")]
+    #[case(i16::MIN.pretty_format(), "-32.8k")]
+    #[case(i16::MAX.pretty_format(), "32.8k")]
+    #[case(i32::MIN.pretty_format(), "-2.1B")]
+    #[case(i32::MAX.pretty_format(), "2.1B")]
+    #[case(i64::MIN.pretty_format(), "-9.2Qi")]
+    #[case(i64::MAX.pretty_format(), "9.2Qi")]
+    #[case(i128::MIN.try_pretty_format_with(Overflow::Scientific).unwrap(), "-1.7e38")]
+    #[case(i128::MAX.try_pretty_format_with(Overflow::Scientific).unwrap(), "1.7e38")]
+    #[cfg_attr(target_pointer_width = "64", case(isize::MIN.pretty_format(), "-9.2Qi"))]
+    #[cfg_attr(target_pointer_width = "64", case(isize::MAX.pretty_format(), "9.2Qi"))]
+    #[case(u8::MIN.pretty_format(), "0")]
+    #[case(u8::MAX.pretty_format(), "255")]
+    #[case(u16::MIN.pretty_format(), "0")]
+    #[case(u16::MAX.pretty_format(), "65.5k")]
+    #[case(u32::MIN.pretty_format(), "0")]
+    #[case(u32::MAX.pretty_format(), "4.3B")]
+    #[case(u64::MIN.pretty_format(), "0")]
+    #[case(u64::MAX.pretty_format(), "18.4Qi")]
+    #[case(u128::MIN.pretty_format(), "0")]
+    #[case(u128::MAX.try_pretty_format_with(Overflow::Scientific).unwrap(), "3.4e38")]
+    #[case(usize::MIN.pretty_format(), "0")]
+    #[cfg_attr(target_pointer_width = "64", case(usize::MAX.pretty_format(), "18.4Qi"))]
+    fn pretty_format_min_and_max_test(#[case] actual: String, #[case] expected: &str) {
+        assert_eq!(actual.as_str(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999_500_000_000_000_000_000)]
+    #[case(-999_500_000_000_000_000_000)]
+    #[case(i128::MIN)]
+    #[case(i128::MAX)]
     #[should_panic]
-    fn format_quadrillion_should_panic(#[case] num: i64) {
+    fn format_sextillion_should_panic(#[case] num: i128) {
         let _ = num.pretty_format();
     }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(0, Ok("0"))]
+    #[case(-25_621_783, Ok("-25.6M"))]
+    #[case(999_499_999_999_999_999_999, Ok("999Qi"))]
+    #[case(999_500_000_000_000_000_000, Err(PrettyNumError::OutOfRange))]
+    #[case(-1_000_000_000_000_000_000_000, Err(PrettyNumError::OutOfRange))]
+    #[case(i128::MAX, Err(PrettyNumError::OutOfRange))]
+    fn try_pretty_format_test(#[case] input: i128, #[case] expected: Result<&str, PrettyNumError>) {
+        assert_eq!(
+            input.try_pretty_format().as_deref().map_err(|&e| e),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(999_499_999_999_999_999_999i128, Overflow::Saturate, "999Qi")]
+    #[case(999_500_000_000_000_000_000i128, Overflow::Saturate, "999Qi+")]
+    #[case(-999_500_000_000_000_000_000i128, Overflow::Saturate, "-999Qi+")]
+    #[case(u128::MAX, Overflow::Saturate, "999Qi+")]
+    #[case(999_499_999_999_999_999_999i128, Overflow::Scientific, "999Qi")]
+    #[case(999_500_000_000_000_000_000i128, Overflow::Scientific, "1e21")]
+    #[case(1_249_999_999_999_999_999_999i128, Overflow::Scientific, "1.2e21")]
+    #[case(-1_250_000_000_000_000_000_000i128, Overflow::Scientific, "-1.3e21")]
+    #[case(99_950_000_000_000_000_000_000i128, Overflow::Scientific, "1e23")]
+    #[case(i128::MIN + 1, Overflow::Scientific, "-1.7e38")]
+    #[case(u128::MAX, Overflow::Scientific, "3.4e38")]
+    fn try_pretty_format_with_overflow_test(
+        #[case] input: impl PrettyNumber,
+        #[case] overflow: Overflow,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            input.try_pretty_format_with(overflow).as_deref(),
+            Ok(expected)
+        );
+    }
+
+    /// The default formatter, with scientific notation beyond the largest suffix.
+    const SCIENTIFIC: PrettyFormatter = PrettyFormatter::new().overflow(Overflow::Scientific);
+
+    /// Generates formatters with each kind of suffix table.
+    fn any_formatter() -> impl Strategy<Value = PrettyFormatter<'static>> {
+        prop_oneof![
+            Just(PrettyFormatter::new()),
+            Just(PrettyFormatter::new().max_decimals(2)),
+            Just(PrettyFormatter::bytes()),
+            Just(PrettyFormatter::binary_bytes()),
+            Just(PrettyFormatter::si()),
+        ]
+    }
+
+    /// Generates every rounding mode.
+    fn any_rounding_mode() -> impl Strategy<Value = RoundingMode> {
+        prop_oneof![
+            Just(RoundingMode::HalfUp),
+            Just(RoundingMode::HalfEven),
+            Just(RoundingMode::Floor),
+            Just(RoundingMode::Ceil),
+            Just(RoundingMode::TowardZero),
+        ]
+    }
+
+    /// Generates integers of every magnitude, rather than mostly ones with 18 or 19 digits.
+    fn any_magnitude() -> impl Strategy<Value = i64> {
+        (any::<i64>(), 0..=19u32)
+            .prop_map(|(n, digits)| 10i64.checked_pow(digits).map_or(n, |limit| n % limit))
+    }
+
+    /// Returns whether the number part of a formatted number, without its sign, suffix or exponent, has a decimal ending in zero.
+    fn ends_in_zero_decimal(formatted: &str) -> bool {
+        let digits = formatted
+            .trim_start_matches('-')
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()
+            .unwrap_or_default();
+
+        digits.contains('.') && digits.ends_with('0')
+    }
+
+    // The properties format into buffers, so that they also run without `alloc`.
+    proptest! {
+        #[test]
+        fn round_trip_within_rounding_error_test(number in any_magnitude()) {
+            let formatter = PrettyFormatter::new();
+            let mut buffer = [0; 16];
+            let formatted = formatter.format_into(number, &mut buffer).unwrap();
+            let parsed = parse_pretty(formatted).unwrap();
+            let error = (i128::from(parsed) - i128::from(number)).abs();
+
+            // At least 2 significant digits are shown, so the error is at most 5%.
+            prop_assert!(error * 20 <= i128::from(number).abs(), "{number} was read back as {parsed}");
+            prop_assert!(formatter.range_of(number).unwrap().contains(&parsed));
+            prop_assert!(formatter.parse_range(formatted).unwrap().contains(&number));
+        }
+
+        #[test]
+        fn parse_range_round_trip_test(formatter in any_formatter(), number in any_magnitude()) {
+            let mut buffer = [0; 16];
+            let formatted = formatter.format_into(number, &mut buffer).unwrap();
+            let range = formatter.parse_range(formatted);
+
+            prop_assert_eq!(&range, &Ok(formatter.range_of(number).unwrap()), "{} was formatted as {}", number, formatted);
+            prop_assert!(range.unwrap().contains(&number));
+        }
+
+        #[test]
+        fn output_length_is_bounded_test(number in any_magnitude()) {
+            let mut buffer = [0; 16];
+
+            // A sign, at most 3 digits and a decimal point, and the longest suffix.
+            prop_assert!(PrettyFormatter::new().format_into(number, &mut buffer).unwrap().len() <= 1 + 4 + 2);
+            prop_assert!(PrettyFormatter::new().format_into(number.unsigned_abs(), &mut buffer).unwrap().len() <= 4 + 2);
+        }
+
+        #[test]
+        fn scientific_output_length_is_bounded_test(number in any::<i128>()) {
+            let mut buffer = [0; 16];
+            let formatted = SCIENTIFIC.format_into(number, &mut buffer).unwrap();
+
+            // A sign, a mantissa such as 1.7, and an exponent of at most 2 digits.
+            prop_assert!(formatted.len() <= 1 + 3 + 3, "{number} was formatted as {formatted}");
+        }
+
+        #[test]
+        fn no_trailing_zero_decimal_test(number in any::<i128>(), float in any::<f64>()) {
+            let mut buffer = [0; 512];
+            let formatted = SCIENTIFIC.format_into(number, &mut buffer).unwrap();
+            prop_assert!(!ends_in_zero_decimal(formatted), "{number} was formatted as {formatted}");
+
+            if let Ok(formatted) = SCIENTIFIC.format_into(float, &mut buffer) {
+                prop_assert!(!ends_in_zero_decimal(formatted), "{float} was formatted as {formatted}");
+            }
+        }
+
+        #[test]
+        fn monotonic_ordering_test(
+            formatter in prop_oneof![Just(PrettyFormatter::new()), Just(PrettyFormatter::binary_bytes())],
+            rounding_mode in any_rounding_mode(),
+            a in any_magnitude(),
+            b in any_magnitude(),
+        ) {
+            let formatter = formatter.rounding_mode(rounding_mode);
+            // The integer part of the displayed value is read back, since binary sizes are rarely whole numbers.
+            let read_back = |number: i64| {
+                let mut buffer = [0; 16];
+                let formatted = formatter.format_into(number, &mut buffer).unwrap();
+                let (negative, integer, _) = formatter.parser().parse_integer_part(formatted).unwrap();
+                if negative { -(integer as i128) } else { integer as i128 }
+            };
+            let (low, high) = (read_back(a.min(b)), read_back(a.max(b)));
+
+            prop_assert!(low <= high, "{a} and {b} were read back as {low} and {high}");
+        }
+    }
 }
@@ -0,0 +1,210 @@
+use crate::Suffixes;
+
+#[rustfmt::skip]
+mod cldr;
+
+/// The decimal separator and suffixes used to format numbers compactly in a language.
+///
+/// The built-in locales are generated from CLDR data, so they match `Intl.NumberFormat` with `notation: "compact"`.
+/// English is always available. Other locales are compiled in only when their cargo feature is enabled, e.g. `de`, `es`, `fr`, `ja`, `ko`,
+/// `zh-hans` or `zh-hant`, or all of them with `all-locales`.
+///
+/// Chinese, Japanese and Korean group numbers by 10,000, so their suffixes display up to 4 integer digits, e.g. "2352万", which are never rounded.
+/// # Examples
+/// ```
+/// # use pretty_num::{Locale, PrettyFormatter};
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let formatter = PrettyFormatter::new().locale(Locale::EN);
+/// assert_eq!(formatter.format(23_520_123), String::from("23.5M"));
+///
+/// // The long form of a locale uses scale words instead.
+/// let formatter = PrettyFormatter::new().locale(Locale::EN).suffixes(Locale::EN.long());
+/// assert_eq!(formatter.format(23_520_123), String::from("23.5 million"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale<'a> {
+    decimal_separator: char,
+    short: Suffixes<'a>,
+    long: Suffixes<'a>,
+}
+
+impl<'a> Locale<'a> {
+    /// Creates a locale from its decimal separator and its short and long suffixes.
+    pub const fn new(decimal_separator: char, short: Suffixes<'a>, long: Suffixes<'a>) -> Self {
+        Locale {
+            decimal_separator,
+            short,
+            long,
+        }
+    }
+
+    /// Returns the character separating the integer part of a number from its decimals.
+    pub const fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Returns the abbreviated suffixes, e.g. "M".
+    pub const fn short(&self) -> Suffixes<'a> {
+        self.short
+    }
+
+    /// Returns the scale words, e.g. "million".
+    pub const fn long(&self) -> Suffixes<'a> {
+        self.long
+    }
+}
+
+impl Default for Locale<'_> {
+    fn default() -> Self {
+        Locale::EN
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Locale;
+    use crate::{suffixes::test::assert_valid, PrettyFormatter};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Locale::EN)]
+    #[cfg_attr(feature = "de", case(Locale::DE))]
+    #[cfg_attr(feature = "es", case(Locale::ES))]
+    #[cfg_attr(feature = "fr", case(Locale::FR))]
+    #[cfg_attr(feature = "ja", case(Locale::JA))]
+    #[cfg_attr(feature = "ko", case(Locale::KO))]
+    #[cfg_attr(feature = "zh-hans", case(Locale::ZH_HANS))]
+    #[cfg_attr(feature = "zh-hant", case(Locale::ZH_HANT))]
+    fn locales_are_valid_test(#[case] locale: Locale) {
+        assert_valid(locale.short());
+        assert_valid(locale.long());
+    }
+
+    #[rstest]
+    #[case(Locale::EN, 23_520_123, "23.5M")]
+    #[cfg_attr(feature = "de", case(Locale::DE, 23_520_123, "23,5\u{a0}Mio."))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 23_520_123, "2352万"))]
+    fn format_locale_into_test(#[case] locale: Locale, #[case] input: i64, #[case] expected: &str) {
+        let mut buffer = [0; 16];
+
+        assert_eq!(
+            PrettyFormatter::new()
+                .locale(locale)
+                .format_into(input, &mut buffer),
+            Ok(expected)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(Locale::EN, 23_520_123, "23.5M", "23.5 million")]
+    #[case(Locale::EN, 1_000, "1K", "1 thousand")]
+    #[case(Locale::EN, -2_500_000_000, "-2.5B", "-2.5 billion")]
+    #[cfg_attr(
+        feature = "de",
+        case(Locale::DE, 23_520_123, "23,5\u{a0}Mio.", "23,5 Millionen")
+    )]
+    #[cfg_attr(
+        feature = "de",
+        case(Locale::DE, 1_000_000, "1\u{a0}Mio.", "1 Million")
+    )]
+    #[cfg_attr(feature = "de", case(Locale::DE, 1_500, "1500", "1,5 Tausend"))]
+    #[cfg_attr(feature = "de", case(Locale::DE, 12_345, "12345", "12,3 Tausend"))]
+    #[cfg_attr(feature = "de", case(Locale::DE, 123_456, "123456", "123 Tausend"))]
+    #[cfg_attr(feature = "de", case(Locale::DE, 999_999, "999999", "1 Million"))]
+    #[cfg_attr(
+        feature = "de",
+        case(Locale::DE, 2_000_000_000, "2\u{a0}Mrd.", "2 Milliarden")
+    )]
+    #[cfg_attr(
+        feature = "es",
+        case(Locale::ES, 23_520_123, "23,5\u{a0}M", "23,5 millones")
+    )]
+    #[cfg_attr(feature = "es", case(Locale::ES, 1_000_000, "1\u{a0}M", "1 millón"))]
+    #[cfg_attr(feature = "es", case(Locale::ES, 1_500, "1,5\u{a0}mil", "1,5 mil"))]
+    #[cfg_attr(
+        feature = "es",
+        case(Locale::ES, 1_000_000_000, "1000\u{a0}M", "1 mil millones")
+    )]
+    #[cfg_attr(
+        feature = "es",
+        case(Locale::ES, 1_234_567_890, "1235\u{a0}M", "1,2 mil millones")
+    )]
+    #[cfg_attr(
+        feature = "es",
+        case(Locale::ES, 123_456_789, "123\u{a0}M", "123 millones")
+    )]
+    #[cfg_attr(
+        feature = "es",
+        case(Locale::ES, 7_700_000_000, "7700\u{a0}M", "7,7 mil millones")
+    )]
+    #[cfg_attr(
+        feature = "fr",
+        case(Locale::FR, 23_520_123, "23,5\u{a0}M", "23,5 millions")
+    )]
+    #[cfg_attr(
+        feature = "fr",
+        case(Locale::FR, 1_500_000, "1,5\u{a0}M", "1,5 million")
+    )]
+    #[cfg_attr(feature = "fr", case(Locale::FR, -1_000_000_000, "-1\u{a0}Md", "-1 milliard"))]
+    #[cfg_attr(feature = "fr", case(Locale::FR, 1_500, "1,5\u{a0}k", "1,5 mille"))]
+    fn format_locale_test(
+        #[case] locale: Locale,
+        #[case] input: i64,
+        #[case] expected_short: &str,
+        #[case] expected_long: &str,
+    ) {
+        let short = PrettyFormatter::new().locale(locale);
+        let long = short.suffixes(locale.long());
+
+        assert_eq!(short.format(input).as_str(), expected_short);
+        assert_eq!(long.format(input).as_str(), expected_long);
+    }
+
+    #[cfg(all(
+        feature = "alloc",
+        any(
+            feature = "ja",
+            feature = "ko",
+            feature = "zh-hans",
+            feature = "zh-hant"
+        )
+    ))]
+    #[rstest]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 23_520_123, "2352万"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 1_234, "1234"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 123_456_789, "1.2億"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 1_234_567, "123万"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 12_345_678_901, "123億"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 12_345, "1.2万"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 1_500_000_000, "15億"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 99_995_000, "1億"))]
+    #[cfg_attr(feature = "ja", case(Locale::JA, 9_999_950_000_000_000, "9999兆+"))]
+    #[cfg_attr(feature = "ko", case(Locale::KO, 23_520_123, "2352만"))]
+    #[cfg_attr(feature = "ko", case(Locale::KO, 1_234, "1.2천"))]
+    #[cfg_attr(feature = "ko", case(Locale::KO, -3_000_000_000_000, "-3조"))]
+    #[cfg_attr(feature = "zh-hans", case(Locale::ZH_HANS, 23_520_123, "2352万"))]
+    #[cfg_attr(
+        feature = "zh-hans",
+        case(Locale::ZH_HANS, 1_234_567_890_123, "1.2万亿")
+    )]
+    #[cfg_attr(feature = "zh-hant", case(Locale::ZH_HANT, 23_520_123, "2352萬"))]
+    #[cfg_attr(feature = "zh-hant", case(Locale::ZH_HANT, 450_000_000, "4.5億"))]
+    fn format_myriad_locale_test(
+        #[case] locale: Locale,
+        #[case] input: i64,
+        #[case] expected: &str,
+    ) {
+        let formatter = PrettyFormatter::new()
+            .locale(locale)
+            .overflow(crate::Overflow::Saturate);
+
+        assert_eq!(formatter.format(input).as_str(), expected);
+        assert_eq!(
+            formatter.suffixes(locale.long()).format(input).as_str(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,159 @@
+// @generated by `cargo run -p pretty-num-codegen` from the CLDR data in `cldr/`. Do not edit by hand.
+
+use super::Locale;
+use crate::{PluralRule, Suffixes};
+
+impl<'a> Locale<'a> {
+    /// English, generated from the CLDR data for `en`.
+    pub const EN: Locale<'static> = Locale::new(
+        '.',
+        Suffixes::localized(
+            &["K", "M", "B", "T"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        ),
+        Suffixes::localized(
+            &["thousand", "million", "billion", "trillion"],
+            None,
+            " ",
+            PluralRule::ExactlyOne,
+        ),
+    );
+
+    /// German, generated from the CLDR data for `de`.
+    #[cfg(feature = "de")]
+    pub const DE: Locale<'static> = Locale::new(
+        ',',
+        Suffixes::localized(
+            &["Mio.", "Mrd.", "Bio."],
+            None,
+            "\u{a0}",
+            PluralRule::ExactlyOne,
+        )
+        .laddered(&[6, 9, 12]),
+        Suffixes::localized(
+            &["Tausend", "Millionen", "Milliarden", "Billionen"],
+            Some(&["Tausend", "Million", "Milliarde", "Billion"]),
+            " ",
+            PluralRule::ExactlyOne,
+        ),
+    );
+
+    /// Spanish, generated from the CLDR data for `es`.
+    #[cfg(feature = "es")]
+    pub const ES: Locale<'static> = Locale::new(
+        ',',
+        Suffixes::localized(
+            &["mil", "M", "mil\u{a0}M", "B"],
+            None,
+            "\u{a0}",
+            PluralRule::ExactlyOne,
+        )
+        .starting_at(&[3, 6, 10, 12]),
+        Suffixes::localized(
+            &["mil", "millones", "mil millones", "billones"],
+            Some(&["mil", "millón", "mil millones", "billón"]),
+            " ",
+            PluralRule::ExactlyOne,
+        ),
+    );
+
+    /// French, generated from the CLDR data for `fr`.
+    #[cfg(feature = "fr")]
+    pub const FR: Locale<'static> = Locale::new(
+        ',',
+        Suffixes::localized(
+            &["k", "M", "Md", "Bn"],
+            None,
+            "\u{a0}",
+            PluralRule::IntegerZeroOrOne,
+        ),
+        Suffixes::localized(
+            &["mille", "millions", "milliards", "billions"],
+            Some(&["mille", "million", "milliard", "billion"]),
+            " ",
+            PluralRule::IntegerZeroOrOne,
+        ),
+    );
+
+    /// Japanese, generated from the CLDR data for `ja`.
+    #[cfg(feature = "ja")]
+    pub const JA: Locale<'static> = Locale::new(
+        '.',
+        Suffixes::localized(
+            &["万", "億", "兆"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .grouped_by(4),
+        Suffixes::localized(
+            &["万", "億", "兆"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .grouped_by(4),
+    );
+
+    /// Korean, generated from the CLDR data for `ko`.
+    #[cfg(feature = "ko")]
+    pub const KO: Locale<'static> = Locale::new(
+        '.',
+        Suffixes::localized(
+            &["천", "만", "억", "조"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .laddered(&[3, 4, 8, 12]),
+        Suffixes::localized(
+            &["천", "만", "억", "조"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .laddered(&[3, 4, 8, 12]),
+    );
+
+    /// Simplified Chinese, generated from the CLDR data for `zh`.
+    #[cfg(feature = "zh-hans")]
+    pub const ZH_HANS: Locale<'static> = Locale::new(
+        '.',
+        Suffixes::localized(
+            &["万", "亿", "万亿"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .grouped_by(4),
+        Suffixes::localized(
+            &["千", "万", "亿", "万亿"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .laddered(&[3, 4, 8, 12]),
+    );
+
+    /// Traditional Chinese, generated from the CLDR data for `zh-Hant`.
+    #[cfg(feature = "zh-hant")]
+    pub const ZH_HANT: Locale<'static> = Locale::new(
+        '.',
+        Suffixes::localized(
+            &["萬", "億", "兆"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .grouped_by(4),
+        Suffixes::localized(
+            &["千", "萬", "億", "兆"],
+            None,
+            "",
+            PluralRule::ExactlyOne,
+        )
+        .laddered(&[3, 4, 8, 12]),
+    );
+}
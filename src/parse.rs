@@ -0,0 +1,510 @@
+use core::{fmt, str::FromStr};
+
+use crate::{Locale, ParseError, PrettyNumber, Suffixes};
+
+/// Parses compact numbers such as `"1.5k"` or `"23.5M"` back into integers.
+///
+/// [`parse_pretty`] is a shortcut for parsing with [`PrettyParser::new`], and [`PrettyFormatter::parser`](crate::PrettyFormatter::parser)
+/// creates a parser that understands everything a formatter can emit.
+///
+/// A parser accepts an optional sign, a number with an optional decimal separator and exponent, e.g. `"1.2e21"`,
+/// then one of its suffixes or sub-units in singular or plural form, optionally preceded by the suffixes' separator or whitespace,
+/// and finally the unit, which is optional. Surrounding whitespace is ignored.
+/// # Examples
+/// ```
+/// # use pretty_num::{ParseError, PrettyFormatter, PrettyParser, Suffixes};
+/// let parser = PrettyParser::new();
+/// assert_eq!(parser.parse("23.5M"), Ok(23_500_000));
+/// assert_eq!(parser.parse("-2k"), Ok(-2_000));
+/// assert_eq!(parser.parse("1.5K"), Err(ParseError::UnknownSuffix));
+///
+/// let parser = parser.case_insensitive(true);
+/// assert_eq!(parser.parse("1.5K"), Ok(1_500));
+///
+/// // A formatter's parser understands its suffixes, separator and unit.
+/// let parser = PrettyFormatter::binary_bytes().parser();
+/// assert_eq!(parser.parse("1.5 KiB"), Ok(1_536));
+/// assert_eq!(parser.parse("512"), Ok(512));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrettyParser<'a> {
+    suffixes: Suffixes<'a>,
+    decimal_separator: char,
+    unit: &'a str,
+    case_insensitive: bool,
+}
+
+impl<'a> PrettyParser<'a> {
+    /// Creates a parser with the default configuration: [`Suffixes::SHORT`], a `.` decimal separator, no unit and case-sensitive suffixes.
+    pub const fn new() -> Self {
+        PrettyParser {
+            suffixes: Suffixes::SHORT,
+            decimal_separator: '.',
+            unit: "",
+            case_insensitive: false,
+        }
+    }
+
+    /// Sets the suffixes recognized after the number.
+    pub const fn suffixes(mut self, suffixes: Suffixes<'a>) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Sets the character separating the integer part of a number from its decimals.
+    /// # Panics
+    /// Panics if `separator` is an ASCII digit.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        assert!(
+            !separator.is_ascii_digit(),
+            "the decimal separator must not be a digit"
+        );
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets a unit that may follow the suffix, or the number when it has no suffix, e.g. `"B"` for "512 B" and "1.5 kB".
+    pub const fn unit(mut self, unit: &'a str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the decimal separator and the short suffixes of a locale.
+    pub const fn locale(self, locale: Locale<'a>) -> Self {
+        self.decimal_separator(locale.decimal_separator())
+            .suffixes(locale.short())
+    }
+
+    /// Sets whether suffixes and the unit are matched regardless of case, so that `"1.5K"` is read like `"1.5k"`.
+    ///
+    /// An exact match is always preferred, so SI prefixes such as `"m"` and `"M"` are still told apart.
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Parses a compact number into an `i64`.
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is empty or malformed, has an unknown suffix, is not a whole number once scaled by its suffix,
+    /// or does not fit in an `i64`.
+    pub fn parse(&self, input: &str) -> Result<i64, ParseError> {
+        let (negative, integer, fractional) = self.parse_integer_part(input)?;
+        if fractional {
+            return Err(ParseError::Fractional);
+        }
+
+        to_signed(negative, integer).ok_or(ParseError::Overflow)
+    }
+
+    /// Parses a compact number into its sign, the integer part of its magnitude, and whether the magnitude has a fractional part,
+    /// e.g. `(false, 1_580, true)` for "1.5433 KiB".
+    pub(crate) fn parse_integer_part(&self, input: &str) -> Result<(bool, u128, bool), ParseError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (mantissa, exponent, truncated, rest) = self.parse_number(unsigned)?;
+        let (multiplier, power) = self.parse_suffix(rest)?;
+
+        let value = mantissa
+            .checked_mul(multiplier)
+            .ok_or(ParseError::Overflow)?;
+        let exponent = exponent.checked_add(power).ok_or(ParseError::Overflow)?;
+        let (integer, fractional) = if value == 0 {
+            (0, false)
+        } else if exponent >= 0 {
+            let integer = 10u128
+                .checked_pow(exponent as u32)
+                .and_then(|scale| value.checked_mul(scale))
+                .ok_or(ParseError::Overflow)?;
+            (integer, false)
+        } else {
+            match 10u128.checked_pow(exponent.unsigned_abs()) {
+                Some(divisor) => (value / divisor, value % divisor != 0),
+                // The divisor is larger than any `u128`, so the magnitude is less than one.
+                None => (0, true),
+            }
+        };
+
+        Ok((negative, integer, fractional || truncated))
+    }
+
+    /// Reads the number at the start of `input` as `mantissa * 10^exponent`, returning them with whether nonzero decimals too small to fit were dropped,
+    /// and the rest of the input.
+    fn parse_number<'s>(&self, input: &'s str) -> Result<(u128, i32, bool, &'s str), ParseError> {
+        let mut mantissa = 0u128;
+        // Zeros after the last nonzero digit are only counted, so that leading and trailing zeros never overflow the mantissa.
+        let mut zeros = 0u32;
+        let mut decimals = 0u32;
+        let mut truncated = false;
+        let mut has_digits = false;
+        let mut in_fraction = false;
+        let mut end = input.len();
+
+        for (index, character) in input.char_indices() {
+            if let Some(digit) = character.to_digit(10) {
+                has_digits = true;
+                decimals += in_fraction as u32;
+                if digit == 0 {
+                    zeros += 1;
+                    continue;
+                }
+
+                let shifted = match mantissa {
+                    0 => Some(0),
+                    _ => 10u128
+                        .checked_pow(zeros + 1)
+                        .and_then(|scale| mantissa.checked_mul(scale)),
+                };
+                match shifted.and_then(|shifted| shifted.checked_add(digit as u128)) {
+                    Some(shifted) => {
+                        mantissa = shifted;
+                        zeros = 0;
+                    }
+                    // Decimals beyond the precision of a `u128` only tell that the number is not whole.
+                    None if in_fraction => {
+                        truncated = true;
+                        zeros += 1;
+                    }
+                    None => return Err(ParseError::Overflow),
+                }
+            } else if character == self.decimal_separator && !in_fraction {
+                in_fraction = true;
+            } else {
+                end = index;
+                break;
+            }
+        }
+
+        if !has_digits {
+            return Err(ParseError::InvalidNumber);
+        }
+
+        let exponent = i32::try_from(i64::from(zeros) - i64::from(decimals))
+            .map_err(|_| ParseError::Overflow)?;
+        let rest = &input[end..];
+        match parse_exponent(rest) {
+            Some((scientific, rest)) => {
+                let exponent = exponent
+                    .checked_add(scientific?)
+                    .ok_or(ParseError::Overflow)?;
+                Ok((mantissa, exponent, truncated, rest))
+            }
+            None => Ok((mantissa, exponent, truncated, rest)),
+        }
+    }
+
+    /// Reads the suffix and unit after the number, returning the value of the suffix as `multiplier * 10^power`.
+    fn parse_suffix(&self, rest: &str) -> Result<(u128, i32), ParseError> {
+        let rest = rest
+            .strip_prefix(self.suffixes.separator())
+            .unwrap_or(rest)
+            .trim_start();
+
+        // The unit is optional, and a suffix may happen to end like it, so the whole rest is tried as well.
+        let without_unit = self.strip_unit(rest);
+        for candidate in [without_unit, Some(rest)].into_iter().flatten() {
+            if let Some(divisor) = self.match_suffix(candidate)? {
+                return Ok(divisor);
+            }
+        }
+
+        Err(ParseError::UnknownSuffix)
+    }
+
+    /// Returns `rest` without the unit at its end, if it ends with the unit.
+    fn strip_unit<'s>(&self, rest: &'s str) -> Option<&'s str> {
+        if self.unit.is_empty() {
+            return None;
+        }
+
+        let split = rest.len().checked_sub(self.unit.len())?;
+        let (head, tail) = (rest.get(..split)?, rest.get(split..)?);
+        self.matches(tail, self.unit).then_some(head.trim_end())
+    }
+
+    /// Returns the value of the suffix equal to `candidate` as `multiplier * 10^power`, or `None` if there is none.
+    fn match_suffix(&self, candidate: &str) -> Result<Option<(u128, i32)>, ParseError> {
+        if candidate.is_empty() {
+            return Ok(Some((1, 0)));
+        }
+
+        let suffixes = self.suffixes;
+        let levels = (1..=suffixes.len())
+            .flat_map(|level| {
+                let divisor = suffixes.divisor(level);
+                [
+                    (suffixes.get(level - 1, false), divisor),
+                    (suffixes.get(level - 1, true), divisor),
+                ]
+            })
+            .chain(
+                (1..=suffixes.sub_units().len())
+                    .map(|level| (suffixes.sub_units()[level - 1], (1, -3 * level as i32))),
+            );
+
+        if let Some((_, divisor)) = levels.clone().find(|&(suffix, _)| suffix == candidate) {
+            return Ok(Some(divisor));
+        }
+
+        let mut matches = levels
+            .filter(|&(suffix, _)| self.matches(suffix, candidate))
+            .map(|(_, divisor)| divisor);
+        match matches.next() {
+            Some(divisor) if matches.all(|other| other == divisor) => Ok(Some(divisor)),
+            Some(_) => Err(ParseError::AmbiguousSuffix),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns whether two pieces of text are equal, regardless of case if the parser is case-insensitive.
+    fn matches(&self, a: &str, b: &str) -> bool {
+        if self.case_insensitive {
+            a.chars()
+                .flat_map(char::to_lowercase)
+                .eq(b.chars().flat_map(char::to_lowercase))
+        } else {
+            a == b
+        }
+    }
+}
+
+/// Returns the integer with the given sign and magnitude, or `None` if it does not fit in an `i64`.
+pub(crate) fn to_signed(negative: bool, magnitude: u128) -> Option<i64> {
+    let magnitude = u64::try_from(magnitude).ok()?;
+    if negative {
+        0i64.checked_sub_unsigned(magnitude)
+    } else {
+        i64::try_from(magnitude).ok()
+    }
+}
+
+/// Reads an exponent such as `e21` or `e-3` at the start of `rest`, returning it with the rest of the input,
+/// or `None` if `rest` does not start with one, so that suffixes such as `E` for exa are left alone.
+fn parse_exponent(rest: &str) -> Option<(Result<i32, ParseError>, &str)> {
+    let unsigned = rest.strip_prefix(['e', 'E'])?;
+    let (negative, digits) = match unsigned.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, unsigned.strip_prefix('+').unwrap_or(unsigned)),
+    };
+
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    if end == 0 {
+        return None;
+    }
+
+    let exponent = digits[..end]
+        .parse::<i32>()
+        .map(|exponent| if negative { -exponent } else { exponent })
+        .map_err(|_| ParseError::Overflow);
+    Some((exponent, &digits[end..]))
+}
+
+impl Default for PrettyParser<'_> {
+    fn default() -> Self {
+        PrettyParser::new()
+    }
+}
+
+/// Parses a compact number such as `"23.5M"` into an `i64` with the default [`PrettyParser`].
+/// # Examples
+/// ```
+/// # use pretty_num::{parse_pretty, ParseError};
+/// assert_eq!(parse_pretty("23.5M"), Ok(23_500_000));
+/// assert_eq!(parse_pretty("10M"), Ok(10_000_000));
+/// assert_eq!(parse_pretty("-1.5k"), Ok(-1_500));
+/// assert_eq!(parse_pretty("1.2345k"), Err(ParseError::Fractional));
+/// assert_eq!(parse_pretty("10x"), Err(ParseError::UnknownSuffix));
+/// ```
+/// # Errors
+/// Returns the same errors as [`PrettyParser::parse`].
+pub fn parse_pretty(input: &str) -> Result<i64, ParseError> {
+    PrettyParser::new().parse(input)
+}
+
+/// An `i64` that is displayed and parsed in compact form, e.g. for limits in config files such as `"10M"`.
+///
+/// Displaying rounds like [`PrettyNumber::pretty_format`], so parsing a displayed value gives back the rounded number.
+/// # Examples
+/// ```
+/// # use pretty_num::PrettyInt;
+/// let limit: PrettyInt = "10M".parse().unwrap();
+/// assert_eq!(limit, PrettyInt(10_000_000));
+/// assert_eq!(PrettyInt(23_520_123).to_string(), "23.5M");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrettyInt(pub i64);
+
+impl FromStr for PrettyInt {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_pretty(s).map(PrettyInt)
+    }
+}
+
+impl fmt::Display for PrettyInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0.pretty(), f)
+    }
+}
+
+impl From<i64> for PrettyInt {
+    fn from(value: i64) -> Self {
+        PrettyInt(value)
+    }
+}
+
+impl From<PrettyInt> for i64 {
+    fn from(value: PrettyInt) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_pretty, PrettyInt, PrettyParser};
+    use crate::{Locale, ParseError, PrettyFormatter, Suffixes};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0", Ok(0))]
+    #[case("534", Ok(534))]
+    #[case("1.5k", Ok(1_500))]
+    #[case("23.5M", Ok(23_500_000))]
+    #[case("  2M  ", Ok(2_000_000))]
+    #[case("-25.6M", Ok(-25_600_000))]
+    #[case("+7B", Ok(7_000_000_000))]
+    #[case("1.2T", Ok(1_200_000_000_000))]
+    #[case("4.5Qa", Ok(4_500_000_000_000_000))]
+    #[case("9.2Qi", Ok(9_200_000_000_000_000_000))]
+    #[case("-9.2Qi", Ok(-9_200_000_000_000_000_000))]
+    #[case("1 k", Ok(1_000))]
+    #[case(".5k", Ok(500))]
+    #[case("1.2e6", Ok(1_200_000))]
+    #[case("1e3k", Ok(1_000_000))]
+    #[case("0.0", Ok(0))]
+    #[case("0.000k", Ok(0))]
+    #[case("1.000000000000000000000000000000000000000000k", Ok(1_000))]
+    #[case("000000000000000000000000000000000000000001.5k", Ok(1_500))]
+    #[case("0.00000000000000000000000000000000000000000015e45", Ok(150))]
+    #[case("1000000000000000000000000000000000000000e-30", Ok(1_000_000_000))]
+    #[case("", Err(ParseError::Empty))]
+    #[case("   ", Err(ParseError::Empty))]
+    #[case("k", Err(ParseError::InvalidNumber))]
+    #[case("-", Err(ParseError::InvalidNumber))]
+    #[case("--5", Err(ParseError::InvalidNumber))]
+    #[case("1.5K", Err(ParseError::UnknownSuffix))]
+    #[case("1..5k", Err(ParseError::UnknownSuffix))]
+    #[case("1,500", Err(ParseError::UnknownSuffix))]
+    #[case("999Qi+", Err(ParseError::UnknownSuffix))]
+    #[case("1.5", Err(ParseError::Fractional))]
+    #[case("1.2345k", Err(ParseError::Fractional))]
+    #[case("1e-3", Err(ParseError::Fractional))]
+    #[case(
+        "1.000000000000000000000000000000000000000001k",
+        Err(ParseError::Fractional)
+    )]
+    #[case(
+        "1.123456789012345678901234567890123456789k",
+        Err(ParseError::Fractional)
+    )]
+    #[case("9.3Qi", Err(ParseError::Overflow))]
+    #[case("1e21", Err(ParseError::Overflow))]
+    #[case(
+        "100000000000000000000000000000000000000001",
+        Err(ParseError::Overflow)
+    )]
+    #[case("1e99999999999", Err(ParseError::Overflow))]
+    fn parse_pretty_test(#[case] input: &str, #[case] expected: Result<i64, ParseError>) {
+        assert_eq!(parse_pretty(input), expected);
+    }
+
+    #[test]
+    fn parse_extremes_test() {
+        assert_eq!(parse_pretty("9223372036854775807"), Ok(i64::MAX));
+        assert_eq!(parse_pretty("-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(
+            parse_pretty("9223372036854775808"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[rstest]
+    #[case(PrettyParser::new().case_insensitive(true), "1.5K", Ok(1_500))]
+    #[case(PrettyParser::new().case_insensitive(true), "2qa", Ok(2_000_000_000_000_000))]
+    #[case(PrettyParser::new().suffixes(Suffixes::SI).case_insensitive(true), "3m", Err(ParseError::Fractional))]
+    #[case(PrettyParser::new().suffixes(Suffixes::SI).case_insensitive(true), "3M", Ok(3_000_000))]
+    #[case(PrettyParser::new().suffixes(Suffixes::SI).case_insensitive(true), "3g", Ok(3_000_000_000))]
+    #[case(PrettyParser::new().suffixes(Suffixes::new(&["m", "M", "b"]).unwrap()).case_insensitive(true), "3B", Ok(3_000_000_000))]
+    #[case(PrettyParser::new().suffixes(Suffixes::new(&["k", "K", "M"]).unwrap()).case_insensitive(true), "3k", Ok(3_000))]
+    #[case(PrettyParser::new().suffixes(Suffixes::new(&["mm", "MM", "b"]).unwrap()).case_insensitive(true), "3Mm", Err(ParseError::AmbiguousSuffix))]
+    #[case(PrettyParser::new().unit("B").case_insensitive(true), "1.5 kb", Ok(1_500))]
+    fn case_insensitive_test(
+        #[case] parser: PrettyParser,
+        #[case] input: &str,
+        #[case] expected: Result<i64, ParseError>,
+    ) {
+        assert_eq!(parser.parse(input), expected);
+    }
+
+    #[rstest]
+    #[case(PrettyFormatter::bytes(), "512 B", Ok(512))]
+    #[case(PrettyFormatter::bytes(), "512", Ok(512))]
+    #[case(PrettyFormatter::bytes(), "1.5 kB", Ok(1_500))]
+    #[case(PrettyFormatter::bytes(), "1.5kB", Ok(1_500))]
+    #[case(PrettyFormatter::bytes(), "2 M", Ok(2_000_000))]
+    #[case(PrettyFormatter::binary_bytes(), "1.5 GiB", Ok(1_610_612_736))]
+    #[case(PrettyFormatter::binary_bytes(), "0.5 KiB", Ok(512))]
+    #[case(
+        PrettyFormatter::binary_bytes(),
+        "1.1 KiB",
+        Err(ParseError::Fractional)
+    )]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::INDIAN), "2.35 Cr", Ok(23_500_000))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::INDIAN), "1 L Cr", Ok(1_000_000_000_000))]
+    #[case(PrettyFormatter::new().suffixes(Suffixes::LONG), "23.5 million", Ok(23_500_000))]
+    #[case(PrettyFormatter::si().unit("s"), "4000ms", Ok(4))]
+    #[case(PrettyFormatter::si().unit("s"), "420µs", Err(ParseError::Fractional))]
+    #[case(PrettyFormatter::si().unit("V"), "3.3kV", Ok(3_300))]
+    #[case(PrettyFormatter::si().unit("V"), "1E", Ok(1_000_000_000_000_000_000))]
+    #[case(PrettyFormatter::new().locale(Locale::EN).suffixes(Locale::EN.long()), "1 thousand", Ok(1_000))]
+    #[cfg_attr(feature = "de", case(PrettyFormatter::new().locale(Locale::DE), "23,5\u{a0}Mio.", Ok(23_500_000)))]
+    #[cfg_attr(feature = "de", case(PrettyFormatter::new().locale(Locale::DE), "23,5 Mio.", Ok(23_500_000)))]
+    #[cfg_attr(feature = "de", case(PrettyFormatter::new().locale(Locale::DE).suffixes(Locale::DE.long()), "1 Million", Ok(1_000_000)))]
+    #[cfg_attr(feature = "de", case(PrettyFormatter::new().locale(Locale::DE).suffixes(Locale::DE.long()), "2 Millionen", Ok(2_000_000)))]
+    #[cfg_attr(feature = "ja", case(PrettyFormatter::new().locale(Locale::JA), "2352万", Ok(23_520_000)))]
+    fn formatter_parser_test(
+        #[case] formatter: PrettyFormatter,
+        #[case] input: &str,
+        #[case] expected: Result<i64, ParseError>,
+    ) {
+        assert_eq!(formatter.parser().parse(input), expected);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(999)]
+    #[case(1_500)]
+    #[case(-23_500_000)]
+    #[case(9_200_000_000_000_000_000)]
+    fn round_trip_test(#[case] input: i64) {
+        let formatted = PrettyInt(input).to_string();
+
+        assert_eq!(formatted.parse(), Ok(PrettyInt(input)));
+    }
+
+    #[test]
+    fn pretty_int_test() {
+        assert_eq!("10M".parse::<PrettyInt>().map(i64::from), Ok(10_000_000));
+        assert_eq!("10x".parse::<PrettyInt>(), Err(ParseError::UnknownSuffix));
+        assert_eq!(format!("{:>6}", PrettyInt::from(1_500)), "  1.5k");
+    }
+}
@@ -0,0 +1,681 @@
+use crate::SuffixError;
+
+/// The largest number of digits a group of [`Suffixes`] can have.
+pub const MAX_GROUP_SIZE: u8 = 8;
+
+/// A table of suffixes for successive powers of 1,000, starting with thousands, of another group size such as 10,000,
+/// or of unevenly spaced powers of ten such as lakhs and crores.
+///
+/// Suffixes can have a separate singular form, chosen by a [`PluralRule`], and a separator placed between the number and the suffix.
+/// [Sub-units](Suffixes::with_sub_units) such as milli and micro can also be used for numbers smaller than one.
+/// # Examples
+/// ```
+/// # use pretty_num::{PrettyFormatter, Suffixes};
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let formatter = PrettyFormatter::new().suffixes(Suffixes::FINANCE);
+/// assert_eq!(formatter.format(23_520_123), String::from("23.5MM"));
+///
+/// // Custom tables can use multi-character suffixes.
+/// let suffixes = Suffixes::new(&["K", "Mil", "Bn"]).unwrap();
+/// let formatter = PrettyFormatter::new().suffixes(suffixes);
+/// assert_eq!(formatter.format(23_520_123), String::from("23.5Mil"));
+///
+/// // Long-form scale words.
+/// let formatter = PrettyFormatter::new().suffixes(Suffixes::LONG);
+/// assert_eq!(formatter.format(23_520_123), String::from("23.5 million"));
+///
+/// // Singular forms for languages that need them.
+/// let suffixes = Suffixes::new(&["Tausend", "Millionen", "Milliarden"])
+///     .and_then(|s| s.with_singular(&["Tausend", "Million", "Milliarde"]))
+///     .and_then(|s| s.with_separator(" "))
+///     .unwrap();
+/// let formatter = PrettyFormatter::new().suffixes(suffixes);
+/// assert_eq!(formatter.format(1_000_000), String::from("1 Million"));
+/// assert_eq!(formatter.format(2_000_000), String::from("2 Millionen"));
+///
+/// // East Asian numbers are grouped by 10,000.
+/// let suffixes = Suffixes::new(&["万", "億", "兆"])
+///     .and_then(|s| s.with_group_size(4))
+///     .unwrap();
+/// let formatter = PrettyFormatter::new().suffixes(suffixes);
+/// assert_eq!(formatter.format(23_520_123), String::from("2352万"));
+/// assert_eq!(formatter.format(1_234_567), String::from("123万"));
+///
+/// // The Indian numbering system has its own ladder of scale words.
+/// let formatter = PrettyFormatter::new().suffixes(Suffixes::INDIAN).max_decimals(2);
+/// assert_eq!(formatter.format(23_520_123), String::from("2.35 Cr"));
+/// assert_eq!(formatter.format(150_000), String::from("1.5 L"));
+///
+/// // SI prefixes also cover numbers smaller than one.
+/// let formatter = PrettyFormatter::new().suffixes(Suffixes::SI);
+/// assert_eq!(formatter.format(0.00042), String::from("420µ"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Suffixes<'a> {
+    plural: &'a [&'a str],
+    singular: &'a [&'a str],
+    separator: &'a str,
+    plural_rule: PluralRule,
+    scale: Scale<'a>,
+    thresholds: &'a [u8],
+    sub_units: &'a [&'a str],
+}
+
+/// The values that the suffixes of a table stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Scale<'a> {
+    /// Every suffix is the same number of digits above the previous one.
+    Uniform(u8),
+    /// Each suffix stands for its own power of ten.
+    Ladder(&'a [u8]),
+    /// Every suffix is 1,024 times the previous one, as in IEC binary prefixes.
+    Binary,
+}
+
+/// Decides when the singular form of a suffix is used.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralRule {
+    /// The singular form is used when the displayed number is exactly 1 without decimals, as in English or German: "1 million" but "1.5 million".
+    #[default]
+    ExactlyOne,
+    /// The singular form is used when the integer part of the displayed number is 0 or 1, as in French: "1,5 million" but "2 millions".
+    IntegerZeroOrOne,
+}
+
+impl PluralRule {
+    /// Returns whether a displayed number with the given integer part and visible decimals takes the singular form.
+    pub(crate) fn is_singular(self, integer: u128, has_visible_decimals: bool) -> bool {
+        match self {
+            PluralRule::ExactlyOne => integer == 1 && !has_visible_decimals,
+            PluralRule::IntegerZeroOrOne => integer <= 1,
+        }
+    }
+}
+
+impl<'a> Suffixes<'a> {
+    /// The default suffixes, from thousands up to quintillions: k, M, B, T, Qa and Qi.
+    pub const SHORT: Suffixes<'static> = Suffixes::unchecked(&["k", "M", "B", "T", "Qa", "Qi"]);
+
+    /// Suffixes commonly used on social media, from thousands up to trillions: k, M, B and T.
+    pub const SOCIAL: Suffixes<'static> = Suffixes::unchecked(&["k", "M", "B", "T"]);
+
+    /// Suffixes commonly used in finance, from thousands up to trillions: K, MM, B and T.
+    pub const FINANCE: Suffixes<'static> = Suffixes::unchecked(&["K", "MM", "B", "T"]);
+
+    /// Uppercase suffixes, from thousands up to trillions: K, M, B and T.
+    pub const UPPERCASE: Suffixes<'static> = Suffixes::unchecked(&["K", "M", "B", "T"]);
+
+    /// The Indian numbering system, from thousands up to lakh crores: K, L, Cr and L Cr for 10^3, 10^5, 10^7 and 10^12, separated by a space.
+    pub const INDIAN: Suffixes<'static> =
+        Suffixes::localized(&["K", "L", "Cr", "L Cr"], None, " ", PluralRule::ExactlyOne)
+            .laddered(&[3, 5, 7, 12]);
+
+    /// SI prefixes for byte sizes, from kilobytes up to exabytes: k, M, G, T, P and E, separated by a space.
+    ///
+    /// Use [`PrettyFormatter::bytes`](crate::PrettyFormatter::bytes) to append the `B` unit.
+    pub const SI_BYTES: Suffixes<'static> = Suffixes::localized(
+        &["k", "M", "G", "T", "P", "E"],
+        None,
+        " ",
+        PluralRule::ExactlyOne,
+    );
+
+    /// IEC binary prefixes for byte sizes, from kibibytes up to exbibytes: Ki, Mi, Gi, Ti, Pi and Ei for successive powers of 1,024, separated by a space.
+    ///
+    /// Use [`PrettyFormatter::binary_bytes`](crate::PrettyFormatter::binary_bytes) to append the `B` unit.
+    pub const IEC_BYTES: Suffixes<'static> = Suffixes::localized(
+        &["Ki", "Mi", "Gi", "Ti", "Pi", "Ei"],
+        None,
+        " ",
+        PluralRule::ExactlyOne,
+    )
+    .binary();
+
+    /// SI prefixes, from kilo up to exa: k, M, G, T, P and E, and from milli down to pico for numbers smaller than one: m, µ, n and p.
+    ///
+    /// Use [`PrettyFormatter::si`](crate::PrettyFormatter::si) to format measurements with them.
+    pub const SI: Suffixes<'static> =
+        Suffixes::unchecked(&["k", "M", "G", "T", "P", "E"]).subdivided(&["m", "µ", "n", "p"]);
+
+    /// Long-form scale words, from thousands up to quintillions: thousand, million, billion, trillion, quadrillion and quintillion.
+    pub const LONG: Suffixes<'static> = Suffixes::localized(
+        &[
+            "thousand",
+            "million",
+            "billion",
+            "trillion",
+            "quadrillion",
+            "quintillion",
+        ],
+        None,
+        " ",
+        PluralRule::ExactlyOne,
+    );
+
+    /// Creates a suffix table without validating it.
+    const fn unchecked(suffixes: &'a [&'a str]) -> Self {
+        Suffixes::localized(suffixes, None, "", PluralRule::ExactlyOne)
+    }
+
+    /// Creates a suffix table with all of its settings without validating it, for the crate's built-in tables.
+    pub(crate) const fn localized(
+        plural: &'a [&'a str],
+        singular: Option<&'a [&'a str]>,
+        separator: &'a str,
+        plural_rule: PluralRule,
+    ) -> Self {
+        Suffixes {
+            plural,
+            singular: match singular {
+                Some(singular) => singular,
+                None => plural,
+            },
+            separator,
+            plural_rule,
+            scale: Scale::Uniform(3),
+            thresholds: &[],
+            sub_units: &[],
+        }
+    }
+
+    /// Sets the group size without validating it, for the crate's built-in tables.
+    pub(crate) const fn grouped_by(mut self, group_size: u8) -> Self {
+        self.scale = Scale::Uniform(group_size);
+        self.thresholds = &[];
+        self
+    }
+
+    /// Makes every suffix 1,024 times the previous one, for the crate's built-in tables.
+    pub(crate) const fn binary(mut self) -> Self {
+        self.scale = Scale::Binary;
+        self.thresholds = &[];
+        self
+    }
+
+    /// Sets the power of ten of each suffix without validating them, for the crate's built-in tables.
+    pub(crate) const fn laddered(mut self, exponents: &'a [u8]) -> Self {
+        self.scale = Scale::Ladder(exponents);
+        self.thresholds = &[];
+        self
+    }
+
+    /// Sets the power of ten from which each suffix is used without validating them, for the crate's built-in tables.
+    pub(crate) const fn starting_at(mut self, thresholds: &'a [u8]) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Sets the sub-unit suffixes without validating them, for the crate's built-in tables.
+    pub(crate) const fn subdivided(mut self, sub_units: &'a [&'a str]) -> Self {
+        self.sub_units = sub_units;
+        self
+    }
+
+    /// Creates a suffix table where `suffixes[0]` is used for thousands, `suffixes[1]` for millions, and so on.
+    /// # Errors
+    /// Returns an error if the table is empty, or if a suffix is empty, is repeated, starts or ends with whitespace,
+    /// starts with `.` or `,`, or contains a digit, `+` or `-`, since those would make the formatted number ambiguous.
+    pub fn new(suffixes: &'a [&'a str]) -> Result<Self, SuffixError> {
+        validate(suffixes)?;
+        Ok(Suffixes::unchecked(suffixes))
+    }
+
+    /// Sets the singular forms of the suffixes, used instead when the [`PluralRule`] calls for it.
+    /// # Errors
+    /// Returns an error if `singular` does not have as many suffixes as the table, or is invalid for the same reasons as in [`Suffixes::new`].
+    pub fn with_singular(mut self, singular: &'a [&'a str]) -> Result<Self, SuffixError> {
+        if singular.len() != self.plural.len() {
+            return Err(SuffixError::LengthMismatch {
+                expected: self.plural.len(),
+                found: singular.len(),
+            });
+        }
+
+        validate(singular)?;
+        self.singular = singular;
+        Ok(self)
+    }
+
+    /// Sets the separator placed between the number and its suffix, e.g. `" "` for long-form scale words.
+    /// # Errors
+    /// Returns [`SuffixError::InvalidSeparator`] if the separator contains a digit or one of `.`, `,`, `+` and `-`.
+    pub fn with_separator(mut self, separator: &'a str) -> Result<Self, SuffixError> {
+        if let Some(character) = separator.chars().find(|&c| is_ambiguous(c)) {
+            return Err(SuffixError::InvalidSeparator { character });
+        }
+
+        self.separator = separator;
+        Ok(self)
+    }
+
+    /// Sets the rule deciding when the singular forms of the suffixes are used.
+    pub const fn with_plural_rule(mut self, plural_rule: PluralRule) -> Self {
+        self.plural_rule = plural_rule;
+        self
+    }
+
+    /// Sets the number of digits in each group, so that `suffixes[0]` is used for `10^group_size`, `suffixes[1]` for `10^(2 * group_size)`, and so on.
+    /// The default is 3, for thousands, millions, etc.
+    ///
+    /// Every integer digit beyond the third is displayed, whatever the formatter's maximum number of significant digits, e.g. "2352万" when grouping by 4.
+    /// # Errors
+    /// Returns [`SuffixError::InvalidGroupSize`] if `group_size` is 0 or greater than [`MAX_GROUP_SIZE`].
+    pub fn with_group_size(self, group_size: u8) -> Result<Self, SuffixError> {
+        if group_size == 0 || group_size > MAX_GROUP_SIZE {
+            return Err(SuffixError::InvalidGroupSize { group_size });
+        }
+
+        Ok(self.grouped_by(group_size))
+    }
+
+    /// Sets the power of ten of each suffix, for numbering systems whose scale words are not evenly spaced.
+    /// For example, the Indian numbering system uses thousands, lakhs and crores: `[3, 5, 7]`.
+    ///
+    /// The largest suffix covers as many digits as the step before it, so `[3, 5, 7]` can format numbers up to 99.9 crores.
+    /// # Errors
+    /// Returns [`SuffixError::LengthMismatch`] if `exponents` does not have as many entries as the table,
+    /// or [`SuffixError::InvalidExponent`] if an exponent is not greater than the previous one (or 0 for the first), or is more than [`MAX_GROUP_SIZE`] above it.
+    pub fn with_exponents(self, exponents: &'a [u8]) -> Result<Self, SuffixError> {
+        if exponents.len() != self.plural.len() {
+            return Err(SuffixError::LengthMismatch {
+                expected: self.plural.len(),
+                found: exponents.len(),
+            });
+        }
+
+        let mut previous = 0;
+        for (index, &exponent) in exponents.iter().enumerate() {
+            if exponent <= previous || exponent - previous > MAX_GROUP_SIZE {
+                return Err(SuffixError::InvalidExponent { index });
+            }
+            previous = exponent;
+        }
+
+        Ok(self.laddered(exponents))
+    }
+
+    /// Sets the power of ten from which each suffix is used, for languages that keep using a suffix beyond the next one's power.
+    /// For example, Spanish displays 10^9 as "1000 M" and only uses "mil M" (10^9) from 10^10: `[3, 6, 10, 12]`.
+    ///
+    /// By default each suffix is used from its own power. Setting the group size or exponents afterwards resets the thresholds.
+    /// # Errors
+    /// Returns [`SuffixError::LengthMismatch`] if `thresholds` does not have as many entries as the table,
+    /// or [`SuffixError::InvalidThreshold`] if a threshold is below the power of its suffix, is not below the power of the next one,
+    /// or is more than [`MAX_GROUP_SIZE`] above the power of the previous one, and for binary tables, which have no powers of ten.
+    pub fn with_thresholds(self, thresholds: &'a [u8]) -> Result<Self, SuffixError> {
+        if thresholds.len() != self.plural.len() {
+            return Err(SuffixError::LengthMismatch {
+                expected: self.plural.len(),
+                found: thresholds.len(),
+            });
+        }
+
+        let suffixes = self.starting_at(thresholds);
+        for (index, &threshold) in thresholds.iter().enumerate() {
+            let level = index + 1;
+            let threshold = threshold as i32;
+            if self.is_binary()
+                || threshold < self.exponent(level)
+                || threshold >= self.exponent(level + 1)
+                || suffixes.step_digits(level - 1) > MAX_GROUP_SIZE as i32
+            {
+                return Err(SuffixError::InvalidThreshold { index });
+            }
+        }
+
+        Ok(suffixes)
+    }
+
+    /// Sets the suffixes used for numbers smaller than one, where `sub_units[0]` is used for thousandths, `sub_units[1]` for millionths, and so on,
+    /// e.g. `["m", "µ", "n"]`.
+    ///
+    /// Sub-units always stand for successive powers of 1/1,000, whatever the group size of the table.
+    /// Numbers smaller than the last sub-unit are displayed with it and a fractional part, e.g. "0.004p".
+    /// # Errors
+    /// Returns an error if `sub_units` is invalid for the same reasons as in [`Suffixes::new`].
+    pub fn with_sub_units(mut self, sub_units: &'a [&'a str]) -> Result<Self, SuffixError> {
+        validate(sub_units)?;
+        self.sub_units = sub_units;
+        Ok(self)
+    }
+
+    /// Returns the suffixes in the table, starting with thousands.
+    pub const fn as_slice(&self) -> &'a [&'a str] {
+        self.plural
+    }
+
+    /// Returns the singular forms of the suffixes in the table, starting with thousands.
+    pub const fn singular(&self) -> &'a [&'a str] {
+        self.singular
+    }
+
+    /// Returns the separator placed between the number and its suffix.
+    pub const fn separator(&self) -> &'a str {
+        self.separator
+    }
+
+    /// Returns the rule deciding when the singular forms of the suffixes are used.
+    pub const fn plural_rule(&self) -> PluralRule {
+        self.plural_rule
+    }
+
+    /// Returns the number of digits in each group, or `None` if the suffixes have their own [exponents](Suffixes::exponents)
+    /// or are binary, like [`Suffixes::IEC_BYTES`].
+    pub const fn group_size(&self) -> Option<u8> {
+        match self.scale {
+            Scale::Uniform(group_size) => Some(group_size),
+            Scale::Ladder(_) | Scale::Binary => None,
+        }
+    }
+
+    /// Returns the power of ten of each suffix, or `None` if the suffixes are evenly spaced by a [group size](Suffixes::group_size)
+    /// or are binary, like [`Suffixes::IEC_BYTES`].
+    pub const fn exponents(&self) -> Option<&'a [u8]> {
+        match self.scale {
+            Scale::Uniform(_) | Scale::Binary => None,
+            Scale::Ladder(exponents) => Some(exponents),
+        }
+    }
+
+    /// Returns the power of ten from which each suffix is used, or an empty slice if each suffix is used from its own power.
+    pub const fn thresholds(&self) -> &'a [u8] {
+        self.thresholds
+    }
+
+    /// Returns whether every suffix is 1,024 times the previous one, like [`Suffixes::IEC_BYTES`].
+    pub const fn is_binary(&self) -> bool {
+        matches!(self.scale, Scale::Binary)
+    }
+
+    /// Returns the suffixes used for numbers smaller than one, starting with thousandths, or an empty slice if there are none.
+    pub const fn sub_units(&self) -> &'a [&'a str] {
+        self.sub_units
+    }
+
+    /// Returns the number of suffixes in the table.
+    pub(crate) const fn len(&self) -> usize {
+        self.plural.len()
+    }
+
+    /// Returns the value of a scale level as `multiplier * 10^power`, where level 0 is unsuffixed and level `n` uses the suffix at index `n - 1`.
+    ///
+    /// Level `len() + 1` is the value at which the largest suffix overflows.
+    pub(crate) const fn divisor(&self, level: usize) -> (u128, i32) {
+        match self.scale {
+            Scale::Binary => (1u128 << (10 * level), 0),
+            _ => (1, self.exponent(level)),
+        }
+    }
+
+    /// Returns the smallest value displayed with a scale level as `multiplier * 10^power`, which is its divisor unless it has a [threshold](Suffixes::with_thresholds).
+    pub(crate) const fn start(&self, level: usize) -> (u128, i32) {
+        match self.scale {
+            Scale::Binary => self.divisor(level),
+            _ => (1, self.start_exponent(level)),
+        }
+    }
+
+    /// Returns how many integer digits a scale level can display before carrying over into the next one.
+    ///
+    /// Binary levels carry over at 1,000 rather than 1,024, so that "1000 KiB" is displayed as "1 MiB".
+    pub(crate) const fn step_digits(&self, level: usize) -> i32 {
+        match self.scale {
+            Scale::Binary => 3,
+            _ => self.start_exponent(level + 1) - self.exponent(level),
+        }
+    }
+
+    /// Returns the power of ten from which a scale level of a decimal table is used.
+    const fn start_exponent(&self, level: usize) -> i32 {
+        if level >= 1 && level <= self.thresholds.len() {
+            self.thresholds[level - 1] as i32
+        } else {
+            self.exponent(level)
+        }
+    }
+
+    /// Returns the power of ten of a scale level of a decimal table.
+    const fn exponent(&self, level: usize) -> i32 {
+        match self.scale {
+            Scale::Uniform(group_size) => group_size as i32 * level as i32,
+            Scale::Binary => 0,
+            Scale::Ladder(_) if level == 0 => 0,
+            Scale::Ladder(exponents) if level <= exponents.len() => exponents[level - 1] as i32,
+            Scale::Ladder(exponents) => {
+                let last = self.exponent(exponents.len());
+                2 * last - self.exponent(exponents.len() - 1)
+            }
+        }
+    }
+
+    /// Returns the suffix at `index`, in its singular form if `singular` is true.
+    pub(crate) fn get(&self, index: usize, singular: bool) -> &'a str {
+        if singular {
+            self.singular[index]
+        } else {
+            self.plural[index]
+        }
+    }
+}
+
+/// Checks that a table of suffixes is non-empty and that every suffix is unambiguous.
+fn validate(suffixes: &[&str]) -> Result<(), SuffixError> {
+    if suffixes.is_empty() {
+        return Err(SuffixError::NoSuffixes);
+    }
+
+    for (index, suffix) in suffixes.iter().enumerate() {
+        if suffix.is_empty() {
+            return Err(SuffixError::EmptySuffix { index });
+        }
+
+        let first = suffix.chars().next().filter(|&c| matches!(c, '.' | ','));
+        let mut edges = suffix.chars().take(1).chain(suffix.chars().next_back());
+        if let Some(character) = suffix
+            .chars()
+            .find(|&c| c.is_ascii_digit() || matches!(c, '+' | '-'))
+            .or(first)
+            .or_else(|| edges.find(|c| c.is_whitespace()))
+        {
+            return Err(SuffixError::InvalidCharacter { index, character });
+        }
+
+        if suffixes[..index].contains(suffix) {
+            return Err(SuffixError::Duplicate { index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether a character could be mistaken for part of the number itself.
+fn is_ambiguous(character: char) -> bool {
+    character.is_ascii_digit() || matches!(character, '.' | ',' | '+' | '-')
+}
+
+impl Default for Suffixes<'_> {
+    fn default() -> Self {
+        Suffixes::SHORT
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::Suffixes;
+    use crate::SuffixError;
+    use rstest::rstest;
+
+    /// Asserts that a table built into the crate passes the same validation as a user-defined one.
+    pub(crate) fn assert_valid(suffixes: Suffixes) {
+        let validated = Suffixes::new(suffixes.as_slice())
+            .and_then(|s| s.with_singular(suffixes.singular()))
+            .and_then(|s| s.with_separator(suffixes.separator()))
+            .map(|s| s.with_plural_rule(suffixes.plural_rule()))
+            .and_then(|s| match (suffixes.group_size(), suffixes.exponents()) {
+                (Some(group_size), _) => s.with_group_size(group_size),
+                (None, Some(exponents)) => s.with_exponents(exponents),
+                (None, None) => Ok(s.binary()),
+            })
+            .and_then(|s| match suffixes.thresholds() {
+                [] => Ok(s),
+                thresholds => s.with_thresholds(thresholds),
+            })
+            .and_then(|s| match suffixes.sub_units() {
+                [] => Ok(s),
+                sub_units => s.with_sub_units(sub_units),
+            });
+
+        assert_eq!(validated, Ok(suffixes));
+    }
+
+    #[rstest]
+    #[case(&[], SuffixError::NoSuffixes)]
+    #[case(&["K", ""], SuffixError::EmptySuffix { index: 1 })]
+    #[case(&["K", "M", "K"], SuffixError::Duplicate { index: 2 })]
+    #[case(&["K", "M2"], SuffixError::InvalidCharacter { index: 1, character: '2' })]
+    #[case(&[" K"], SuffixError::InvalidCharacter { index: 0, character: ' ' })]
+    #[case(&["K", ".M", "B"], SuffixError::InvalidCharacter { index: 1, character: '.' })]
+    #[case(&[",K"], SuffixError::InvalidCharacter { index: 0, character: ',' })]
+    #[case(&["-K"], SuffixError::InvalidCharacter { index: 0, character: '-' })]
+    #[case(&["thousand "], SuffixError::InvalidCharacter { index: 0, character: ' ' })]
+    fn invalid_suffixes_test(#[case] suffixes: &[&str], #[case] expected: SuffixError) {
+        assert_eq!(Suffixes::new(suffixes), Err(expected));
+    }
+
+    #[rstest]
+    #[case(Suffixes::SHORT)]
+    #[case(Suffixes::SOCIAL)]
+    #[case(Suffixes::FINANCE)]
+    #[case(Suffixes::UPPERCASE)]
+    #[case(Suffixes::LONG)]
+    #[case(Suffixes::INDIAN)]
+    #[case(Suffixes::SI_BYTES)]
+    #[case(Suffixes::IEC_BYTES)]
+    #[case(Suffixes::SI)]
+    fn presets_are_valid_test(#[case] preset: Suffixes) {
+        assert_valid(preset);
+    }
+
+    #[test]
+    fn inner_whitespace_is_valid_test() {
+        assert!(Suffixes::new(&["mil", "millones", "mil millones"]).is_ok());
+    }
+
+    #[test]
+    fn abbreviation_dots_are_valid_test() {
+        assert!(Suffixes::new(&["Tsd.", "Mio.", "Mrd."]).is_ok());
+    }
+
+    #[rstest]
+    #[case(&["Million"], SuffixError::LengthMismatch { expected: 2, found: 1 })]
+    #[case(&["Tausend", "Million", "Milliarde"], SuffixError::LengthMismatch { expected: 2, found: 3 })]
+    #[case(&["Tausend", ""], SuffixError::EmptySuffix { index: 1 })]
+    fn invalid_singular_test(#[case] singular: &[&str], #[case] expected: SuffixError) {
+        let suffixes = Suffixes::new(&["Tausend", "Millionen"]).unwrap();
+
+        assert_eq!(suffixes.with_singular(singular), Err(expected));
+    }
+
+    #[rstest]
+    #[case(" x ", Ok(" x "))]
+    #[case("\u{a0}", Ok("\u{a0}"))]
+    #[case("-", Err(SuffixError::InvalidSeparator { character: '-' }))]
+    #[case(" 0", Err(SuffixError::InvalidSeparator { character: '0' }))]
+    fn separator_test(#[case] separator: &str, #[case] expected: Result<&str, SuffixError>) {
+        let suffixes = Suffixes::SHORT.with_separator(separator);
+
+        assert_eq!(suffixes.map(|s| s.separator()), expected);
+    }
+
+    #[rstest]
+    #[case(1, Ok(1))]
+    #[case(4, Ok(4))]
+    #[case(8, Ok(8))]
+    #[case(0, Err(SuffixError::InvalidGroupSize { group_size: 0 }))]
+    #[case(9, Err(SuffixError::InvalidGroupSize { group_size: 9 }))]
+    fn group_size_test(#[case] group_size: u8, #[case] expected: Result<u8, SuffixError>) {
+        let suffixes = Suffixes::SHORT.with_group_size(group_size);
+
+        assert_eq!(suffixes.map(|s| s.group_size()), expected.map(Some));
+    }
+
+    #[rstest]
+    #[case(&[3, 5, 7], Ok(&[3, 5, 7][..]))]
+    #[case(&[1, 9, 17], Ok(&[1, 9, 17][..]))]
+    #[case(&[3, 5], Err(SuffixError::LengthMismatch { expected: 3, found: 2 }))]
+    #[case(&[0, 5, 7], Err(SuffixError::InvalidExponent { index: 0 }))]
+    #[case(&[3, 3, 7], Err(SuffixError::InvalidExponent { index: 1 }))]
+    #[case(&[3, 5, 4], Err(SuffixError::InvalidExponent { index: 2 }))]
+    #[case(&[3, 5, 14], Err(SuffixError::InvalidExponent { index: 2 }))]
+    fn exponents_test(#[case] exponents: &[u8], #[case] expected: Result<&[u8], SuffixError>) {
+        let suffixes = Suffixes::new(&["K", "L", "Cr"]).and_then(|s| s.with_exponents(exponents));
+
+        assert_eq!(suffixes.map(|s| s.exponents()), expected.map(Some));
+    }
+
+    #[test]
+    fn exponents_replace_group_size_test() {
+        let suffixes = Suffixes::SHORT.with_group_size(4).unwrap();
+        let suffixes = Suffixes::new(&["K", "L", "Cr"])
+            .and_then(|s| s.with_exponents(&[3, 5, 7]))
+            .and_then(|s| s.with_group_size(suffixes.group_size().unwrap()))
+            .unwrap();
+
+        assert_eq!(
+            (suffixes.group_size(), suffixes.exponents()),
+            (Some(4), None)
+        );
+    }
+
+    #[rstest]
+    #[case(&[3, 6, 10, 12], Ok(&[3, 6, 10, 12][..]))]
+    #[case(&[3, 6, 9, 12], Ok(&[3, 6, 9, 12][..]))]
+    #[case(&[3, 6, 10], Err(SuffixError::LengthMismatch { expected: 4, found: 3 }))]
+    #[case(&[3, 5, 10, 12], Err(SuffixError::InvalidThreshold { index: 1 }))]
+    #[case(&[3, 6, 12, 12], Err(SuffixError::InvalidThreshold { index: 2 }))]
+    #[case(&[2, 6, 10, 12], Err(SuffixError::InvalidThreshold { index: 0 }))]
+    fn thresholds_test(#[case] thresholds: &[u8], #[case] expected: Result<&[u8], SuffixError>) {
+        let suffixes =
+            Suffixes::new(&["mil", "M", "mil M", "B"]).and_then(|s| s.with_thresholds(thresholds));
+
+        assert_eq!(suffixes.map(|s| s.thresholds()), expected);
+    }
+
+    #[test]
+    fn binary_thresholds_test() {
+        assert_eq!(
+            Suffixes::IEC_BYTES.with_thresholds(&[3, 6, 9, 12, 15, 18]),
+            Err(SuffixError::InvalidThreshold { index: 0 })
+        );
+    }
+
+    #[test]
+    fn group_size_resets_thresholds_test() {
+        let suffixes = Suffixes::new(&["mil", "M", "mil M", "B"])
+            .and_then(|s| s.with_thresholds(&[3, 6, 10, 12]))
+            .and_then(|s| s.with_group_size(3))
+            .unwrap();
+
+        assert!(suffixes.thresholds().is_empty());
+    }
+
+    #[rstest]
+    #[case(&["m", "µ", "n"], Ok(&["m", "µ", "n"][..]))]
+    #[case(&[], Err(SuffixError::NoSuffixes))]
+    #[case(&["m", "m"], Err(SuffixError::Duplicate { index: 1 }))]
+    #[case(&["m", "u2"], Err(SuffixError::InvalidCharacter { index: 1, character: '2' }))]
+    fn sub_units_test(#[case] sub_units: &[&str], #[case] expected: Result<&[&str], SuffixError>) {
+        let suffixes = Suffixes::SHORT.with_sub_units(sub_units);
+
+        assert_eq!(suffixes.map(|s| s.sub_units()), expected);
+    }
+
+    #[test]
+    fn long_table_test() {
+        let suffixes = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+        assert_eq!(Suffixes::new(&suffixes).map(|s| s.as_slice().len()), Ok(10));
+    }
+}